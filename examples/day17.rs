@@ -0,0 +1,5 @@
+use aoc2023::{day17::Day17, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day17>()
+}
@@ -0,0 +1,5 @@
+use aoc2023::{day18::Day18, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day18>()
+}
@@ -0,0 +1,5 @@
+use aoc2023::{day19::Day19, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day19>()
+}
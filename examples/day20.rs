@@ -0,0 +1,5 @@
+use aoc2023::{day20::Day20, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day20>()
+}
@@ -0,0 +1,5 @@
+use aoc2023::{day21::Day21, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day21>()
+}
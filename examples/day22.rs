@@ -0,0 +1,5 @@
+use aoc2023::{day22::Day22, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day22>()
+}
@@ -0,0 +1,5 @@
+use aoc2023::{day23::Day23, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day23>()
+}
@@ -0,0 +1,5 @@
+use aoc2023::{day24::Day24, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day24>()
+}
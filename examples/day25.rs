@@ -0,0 +1,5 @@
+use aoc2023::{day25::Day25, run};
+
+fn main() -> eyre::Result<()> {
+    run::<Day25>()
+}
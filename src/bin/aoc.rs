@@ -0,0 +1,171 @@
+//! Runs any day of the calendar against its bundled input or a file of your choosing.
+//!
+//! ```text
+//! aoc run 10 --part 2 --input path/to/input.txt
+//! aoc run all
+//! ```
+
+use std::time::{Duration, Instant};
+
+use aoc2023::{
+    input::InputSource,
+    runner::{self, Day, Part, DAYS},
+};
+use eyre::{bail, ContextCompat, Result, WrapErr};
+use itertools::Itertools;
+use tap::prelude::*;
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <1|2>] [--input <path|->]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Run {
+    target: Target,
+    parts: Vec<Part>,
+    input: InputSource,
+}
+
+impl Run {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        match args.next().as_deref() {
+            Some("run") => {}
+            Some(other) => bail!("unknown command: {other:?}\n{USAGE}"),
+            None => bail!(USAGE),
+        }
+        let target = match args.next().context(USAGE)?.as_str() {
+            "all" => Target::All,
+            day => day
+                .parse()
+                .map(Target::Day)
+                .wrap_err_with(|| format!("bad day: {day:?}\n{USAGE}"))?,
+        };
+        let mut run = Self {
+            target,
+            parts: Part::all().to_vec(),
+            input: InputSource::Bundled,
+        };
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .with_context(|| format!("missing value for {flag}\n{USAGE}"))?;
+            match flag.as_str() {
+                "--part" => {
+                    run.parts = value
+                        .parse()
+                        .wrap_err_with(|| format!("bad part: {value:?}"))
+                        .map(|part| vec![part])?
+                }
+                "--input" => run.input = InputSource::from(value),
+                other => bail!("unknown flag: {other:?}\n{USAGE}"),
+            }
+        }
+        Ok(run)
+    }
+}
+
+struct Row {
+    day: u8,
+    /// `None` for days without a bundled input.
+    answers: Option<Result<Vec<String>>>,
+    elapsed: Duration,
+}
+
+/// Solves `day` against its bundled input, measuring how long it took.
+fn timed(day: &Day, parts: &[Part]) -> Row {
+    let started = Instant::now();
+    let answers = day.input.map(|input| (day.solve)(input, parts));
+    Row {
+        day: day.day,
+        answers,
+        elapsed: started.elapsed(),
+    }
+}
+
+fn summary(rows: &[Row], parts: &[Part]) -> String {
+    let header = ["day".to_owned()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("part {part}")))
+        .chain(["time".to_owned()])
+        .collect_vec();
+    let cells = rows
+        .iter()
+        .map(
+            |Row {
+                 day,
+                 answers,
+                 elapsed,
+             }| {
+                [day.to_string()]
+                    .into_iter()
+                    .chain(match answers {
+                        Some(Ok(answers)) => answers.clone(),
+                        // only the first line, a located parse error would break the table
+                        Some(Err(error)) => error.to_string().lines().next().map_or_else(
+                            || vec!["error".to_owned(); parts.len()],
+                            |line| vec![format!("error: {line}"); parts.len()],
+                        ),
+                        None => vec!["no input".to_owned(); parts.len()],
+                    })
+                    .chain([match answers {
+                        Some(_) => format!("{elapsed:.2?}"),
+                        None => "-".to_owned(),
+                    }])
+                    .collect_vec()
+            },
+        )
+        .collect_vec();
+    let widths = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&cells)
+                .map(|row| row[column].len())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect_vec();
+    let line = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .join(" | ")
+            .trim_end()
+            .to_owned()
+    };
+    std::iter::once(line(&header))
+        .chain([widths.iter().map(|width| "-".repeat(*width)).join("-|-")])
+        .chain(cells.iter().map(|row| line(row)))
+        .join("\n")
+}
+
+fn main() -> Result<()> {
+    let Run {
+        target,
+        parts,
+        input,
+    } = Run::from_args(std::env::args().skip(1))?;
+    match target {
+        Target::Day(day) => {
+            let day = runner::day(day).with_context(|| format!("day {day} is not solved yet"))?;
+            let input = input.read(day.input)?;
+            (day.solve)(&input, &parts)?
+                .into_iter()
+                .zip(&parts)
+                .for_each(|(answer, part)| println!("day {} part {part}: {answer}", day.day));
+        }
+        Target::All => {
+            if input != InputSource::Bundled {
+                bail!("--input can only be used when running a single day");
+            }
+            DAYS.iter()
+                .map(|day| timed(day, &parts))
+                .collect_vec()
+                .pipe(|rows| println!("{}", summary(&rows, &parts)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use eyre::Result;
+use tap::prelude::*;
+
+use crate::{parse::ParseError, Solution};
+
+const DIGITS: &[(u32, &str)] = &[
+    (0, "0"),
+    (1, "1"),
+    (2, "2"),
+    (3, "3"),
+    (4, "4"),
+    (5, "5"),
+    (6, "6"),
+    (7, "7"),
+    (8, "8"),
+    (9, "9"),
+    (0, "zero"),
+    (1, "one"),
+    (2, "two"),
+    (3, "three"),
+    (4, "four"),
+    (5, "five"),
+    (6, "six"),
+    (7, "seven"),
+    (8, "eight"),
+    (9, "nine"),
+];
+
+fn next_digit(input: &str) -> Option<u32> {
+    DIGITS
+        .iter()
+        .copied()
+        .find_map(|(i, digit)| input.strip_prefix(digit).map(|_| i))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day1.txt"));
+
+    type Input<'input> = Vec<&'input str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .pipe(Ok)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        input
+            .iter()
+            .filter_map(|line| {
+                line.chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .collect::<Vec<_>>()
+                    .pipe(|digits| digits.first().copied().zip(digits.last().copied()))
+            })
+            .map(|(first, last)| first * 10 + last)
+            .sum::<u32>()
+            .pipe(Ok)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        input
+            .iter()
+            .filter_map(|line| {
+                line.char_indices()
+                    .filter_map(|(start, _)| next_digit(&line[start..]))
+                    .collect::<Vec<_>>()
+                    .pipe(|v| v.first().copied().zip(v.last().copied()))
+            })
+            .map(|(first, last)| first * 10 + last)
+            .sum::<u32>()
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,389 @@
+use std::{collections::HashSet, iter::successors, ops::Div};
+
+use eyre::{bail, ContextCompat, Result};
+use itertools::Itertools;
+use tap::{Pipe as _, Tap as _};
+
+use crate::{
+    grid::{Direction, Grid, Position},
+    math::interior_points,
+    parse::ParseError,
+    Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tile {
+    ///  `|` is a vertical pipe connecting north and south.
+    ///  `-` is a horizontal pipe connecting east and west.
+    ///  `L` is a 90-degree bend connecting north and east.
+    ///  `J` is a 90-degree bend connecting north and west.
+    ///  `7` is a 90-degree bend connecting south and west.
+    ///  `F` is a 90-degree bend connecting south and east.
+    Pipe([Direction; 2]),
+    ///  . is ground; there is no pipe in this tile.
+    Ground,
+    ///  S is the starting position of the animal;
+    /// there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+    Start,
+}
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pipe([Direction::North, Direction::South]) => '┃',
+            Self::Pipe([Direction::West, Direction::East]) => '━',
+            Self::Pipe([Direction::North, Direction::East]) => '┗',
+            Self::Pipe([Direction::North, Direction::West]) => '┛',
+            Self::Pipe([Direction::South, Direction::West]) => '┓',
+            Self::Pipe([Direction::South, Direction::East]) => '┏',
+            Self::Pipe(_) => unreachable!(),
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+        }
+        .pipe(|c| write!(f, "{c}"))
+    }
+}
+
+impl std::fmt::Debug for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pipe([Direction::North, Direction::South]) => '|',
+            Self::Pipe([Direction::West, Direction::East]) => '-',
+            Self::Pipe([Direction::North, Direction::East]) => 'L',
+            Self::Pipe([Direction::North, Direction::West]) => 'J',
+            Self::Pipe([Direction::South, Direction::West]) => '7',
+            Self::Pipe([Direction::South, Direction::East]) => 'F',
+            Self::Pipe(_) => unreachable!(),
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+        }
+        .pipe(|c| write!(f, "{c}"))
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = eyre::Report;
+
+    fn try_from(value: char) -> Result<Self> {
+        use Direction::*;
+        match value {
+            '|' => Ok(Self::Pipe([North, South])),
+            '-' => Ok(Self::Pipe([West, East])),
+            'L' => Ok(Self::Pipe([North, East])),
+            'J' => Ok(Self::Pipe([North, West])),
+            '7' => Ok(Self::Pipe([South, West])),
+            'F' => Ok(Self::Pipe([South, East])),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            other => bail!("unexpected tile: '{other}'"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Input {
+    grid: Grid<Tile>,
+    /// Where the `S` was, which stays known once the tile is resolved to its pipe.
+    start: Option<Position>,
+}
+
+impl std::fmt::Debug for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.grid
+            .rows()
+            .try_for_each(|line| writeln!(f, "{line:?}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PositionedTile {
+    pub tile: Tile,
+    pub position: Position,
+}
+
+impl Input {
+    pub fn new(grid: Grid<Tile>) -> Self {
+        let start = grid
+            .iter()
+            .find(|&(_, &tile)| tile == Tile::Start)
+            .map(|(position, _)| position);
+        Self { grid, start }
+    }
+
+    fn get(&self, position: Position) -> Option<PositionedTile> {
+        self.grid
+            .get(position)
+            .copied()
+            .map(|tile| PositionedTile { tile, position })
+    }
+
+    /// The pipe hidden under the start tile, going by which neighbours connect back into it.
+    pub fn start_shape(&self) -> Result<Tile> {
+        let start = self.start.context("no start tile")?;
+        let connected = self
+            .grid
+            .neighbours(start)
+            .filter(|&(neighbour, direction)| {
+                matches!(
+                    self.grid.get(neighbour),
+                    Some(Tile::Pipe(connections)) if connections.contains(&direction.opposite())
+                )
+            })
+            .map(|(_, direction)| direction)
+            .collect_vec();
+        let &[one, other] = connected.as_slice() else {
+            bail!(
+                "{} pipes connect to the start at {start:?}, expected 2",
+                connected.len()
+            );
+        };
+        "|-LJ7F"
+            .chars()
+            .filter_map(|c| Tile::try_from(c).ok())
+            .find(|tile| matches!(tile, Tile::Pipe(connections) if connections.contains(&one) && connections.contains(&other)))
+            .context("no pipe connects two directions")
+    }
+
+    /// Rewrites the start tile as the pipe under it, see [`Self::start_shape`].
+    pub fn resolve_start(&mut self) -> Result<Position> {
+        let shape = self.start_shape()?;
+        let start = self.start.context("no start tile")?;
+        self.grid.set(start, shape)?;
+        Ok(start)
+    }
+
+    /// The maze with the pipe under the start tile filled in.
+    pub fn resolved(&self) -> Result<Self> {
+        let mut resolved = self.clone();
+        resolved.resolve_start()?;
+        Ok(resolved)
+    }
+}
+
+fn popped_array<T: PartialEq>(array: [T; 2], element: &T) -> Option<T> {
+    array
+        .contains(element)
+        .then_some(())
+        .and_then(|_| array.into_iter().find(|needle| needle != element))
+}
+
+impl Tile {
+    /// The direction a pipe leads on in when entered heading in `direction`.
+    fn entered_from(self, direction: Direction) -> Option<Direction> {
+        match self {
+            Tile::Pipe(connections) => popped_array(connections, &direction.opposite()),
+            Tile::Ground | Tile::Start => None,
+        }
+    }
+}
+
+impl Input {
+    /// Follows the pipes out of `from`, `None` unless they lead back into it.
+    fn walk_loop(&self, from: Position) -> Option<Vec<PositionedTile>> {
+        let Some(Tile::Pipe([heading, _])) = self.grid.get(from).copied() else {
+            return None;
+        };
+        let walk = successors(Some((from, heading)), |&(position, heading)| {
+            let next = self.grid.neighbour(position, heading)?;
+            let heading = self.grid.get(next)?.entered_from(heading)?;
+            (next != from).then_some((next, heading))
+        })
+        .collect_vec();
+        let &(last, heading) = walk.last()?;
+        let back = self.grid.neighbour(last, heading)?;
+        let closed = back == from && self.grid.get(from)?.entered_from(heading).is_some();
+        closed.then(|| {
+            walk.into_iter()
+                .filter_map(|(position, _)| self.get(position))
+                .collect_vec()
+        })
+    }
+
+    /// The loop through the start tile, on a maze with the start resolved.
+    fn main_loop(&self) -> Result<Vec<PositionedTile>> {
+        let start = self.start.context("no start tile")?;
+        self.walk_loop(start)
+            .with_context(|| format!("pipes from the start at {start:?} do not form a loop"))
+    }
+
+    /// Number of tiles enclosed by the main loop, counted with `method`.
+    pub fn enclosed(&self, method: Interior) -> Result<usize> {
+        let resolved = self.resolved()?;
+        let the_loop = resolved.main_loop()?;
+        match method {
+            Interior::Area => the_loop
+                .iter()
+                .map(|tile| tile.position)
+                .collect_vec()
+                .pipe(|positions| enclosed_by_area(&positions)),
+            Interior::Scanline => Ok(enclosed_by_scanline(&the_loop, resolved.grid.width())),
+        }
+    }
+}
+
+/// A closed loop of pipes anywhere in the maze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipeLoop {
+    /// Tiles in the order the loop runs through them.
+    pub tiles: Vec<Position>,
+    pub enclosed: usize,
+}
+
+impl PipeLoop {
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+}
+
+/// Every pipe in the maze, sorted into closed loops and dangling fragments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Survey {
+    pub loops: Vec<PipeLoop>,
+    /// Runs of connected pipes with loose ends, starting from the end that comes first
+    /// reading row by row.
+    pub fragments: Vec<Vec<Position>>,
+}
+
+impl Input {
+    /// Neighbours `position` connects to that connect back to it.
+    fn connected(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        let connections = match self.grid.get(position) {
+            Some(Tile::Pipe(connections)) => connections.to_vec(),
+            _ => vec![],
+        };
+        connections.into_iter().filter_map(move |direction| {
+            self.grid.neighbour(position, direction).filter(|&next| {
+                self.grid
+                    .get(next)
+                    .and_then(|tile| tile.entered_from(direction))
+                    .is_some()
+            })
+        })
+    }
+
+    /// Tiles reached following the pipes from `position` in one direction, which ends at a
+    /// loose end unless `position` is on a loop.
+    fn follow(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        successors(Some((None, position)), |&(previous, current)| {
+            self.connected(current)
+                .find(|&next| Some(next) != previous)
+                .map(|next| (Some(current), next))
+        })
+        .map(|(_, position)| position)
+    }
+
+    /// Sorts every pipe into the closed loop or the fragment it belongs to.
+    ///
+    /// The start tile counts as the pipe under it when that can be told, and as a dead end
+    /// otherwise. Every pipe connects to at most two others, so the pipes connected to each
+    /// other either close into a loop or run between two loose ends.
+    pub fn survey(&self) -> Result<Survey> {
+        let maze = self.resolved().unwrap_or_else(|_| self.clone());
+        let mut seen = HashSet::new();
+        let mut survey = Survey::default();
+        for position in maze.grid.positions() {
+            if !matches!(maze.grid.get(position), Some(Tile::Pipe(_))) || seen.contains(&position) {
+                continue;
+            }
+            if let Some(the_loop) = maze.walk_loop(position) {
+                let tiles = the_loop.iter().map(|tile| tile.position).collect_vec();
+                seen.extend(tiles.iter().copied());
+                let enclosed = enclosed_by_area(&tiles)?;
+                survey.loops.push(PipeLoop { tiles, enclosed });
+                continue;
+            }
+            // back up to a loose end, then follow the pipes to the other one
+            let end = maze.follow(position).last().unwrap_or(position);
+            let fragment = maze.follow(end).collect_vec().tap_mut(|fragment| {
+                if fragment.last() < fragment.first() {
+                    fragment.reverse();
+                }
+            });
+            seen.extend(fragment.iter().copied());
+            survey.fragments.push(fragment);
+        }
+        Ok(survey)
+    }
+}
+
+/// How to count the tiles enclosed by a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interior {
+    /// Lattice points inside the polygon through the centres of the loop's tiles, see
+    /// [`interior_points`].
+    Area,
+    /// Along each row, a tile is inside once the loop has been crossed an odd number of times.
+    /// Only tiles connecting north count as a crossing, so `L-7` is one crossing and `L-J` none.
+    Scanline,
+}
+
+fn enclosed_by_area(positions: &[Position]) -> Result<usize> {
+    positions
+        .iter()
+        .map(|position| (position.row as i128, position.column as i128))
+        .collect_vec()
+        .pipe(|corners| interior_points(&corners, positions.len() as i128))
+        .pipe(usize::try_from)
+        .ok()
+        .context("loop too short to enclose anything")
+}
+
+fn enclosed_by_scanline(the_loop: &[PositionedTile], width: usize) -> usize {
+    let on_loop = the_loop
+        .iter()
+        .map(|tile| tile.position)
+        .collect::<HashSet<_>>();
+    let crossings = the_loop
+        .iter()
+        .filter(|tile| matches!(tile.tile, Tile::Pipe(connections) if connections.contains(&Direction::North)))
+        .map(|tile| tile.position)
+        .collect::<HashSet<_>>();
+    let rows = the_loop
+        .iter()
+        .map(|tile| tile.position.row)
+        .max()
+        .unwrap_or_default();
+    (0..=rows)
+        .map(|row| {
+            (0..width)
+                .map(|column| Position::new(row, column))
+                .fold((false, 0), |(inside, enclosed), position| {
+                    match (on_loop.contains(&position), inside) {
+                        (true, _) => (inside ^ crossings.contains(&position), enclosed),
+                        (false, true) => (inside, enclosed + 1),
+                        (false, false) => (inside, enclosed),
+                    }
+                })
+                .1
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day10.txt"));
+
+    type Input<'input> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse::<Grid<Tile>>().map(Input::new)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        input
+            .resolved()?
+            .main_loop()
+            .map(|the_loop| the_loop.len().div(2))
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        input.enclosed(Interior::Area)
+    }
+}
@@ -0,0 +1,116 @@
+use eyre::{bail, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{grid::Grid, parse::ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Point {
+    row: i64,
+    column: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Galaxy {
+    id: usize,
+    position: Point,
+}
+
+impl Point {
+    fn distance(self, other: &Self) -> u64 {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+    fn rows(&self, other: &Self) -> impl Iterator<Item = u64> {
+        (self.row.min(other.row)..(self.row.max(other.row))).map(|v| v.try_conv::<_>().unwrap())
+    }
+    fn columns(&self, other: &Self) -> impl Iterator<Item = u64> {
+        (self.column.min(other.column)..(self.column.max(other.column)))
+            .map(|v| v.try_conv::<_>().unwrap())
+    }
+}
+
+fn columns<T: Copy>(input: &[Vec<T>]) -> impl Iterator<Item = Vec<T>> + '_ {
+    input.first().expect("cannot be empty").len().pipe(|len| {
+        (0..len).map(|idx| {
+            input
+                .iter()
+                .map(|line| line.get(idx).expect("bad column"))
+                .copied()
+                .collect_vec()
+        })
+    })
+}
+
+/// Sum of distances between every pair of galaxies, with each empty row and column
+/// replaced by `expansion` copies of itself.
+fn total_distance(lines: &[Vec<char>], expansion: u64) -> u64 {
+    let expanded_rows = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(row, line)| (!line.contains(&'#')).then_some(row))
+        .collect_vec();
+    let expanded_columns = columns(lines)
+        .enumerate()
+        .filter_map(|(row, line)| (!line.contains(&'#')).then_some(row))
+        .collect_vec();
+    lines
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.iter().enumerate().filter_map(move |(column, c)| {
+                c.eq(&'#').then_some(Point {
+                    row: row.try_conv().unwrap(),
+                    column: column.try_conv().unwrap(),
+                })
+            })
+        })
+        .enumerate()
+        .map(|(id, position)| Galaxy {
+            id: id + 1,
+            position,
+        })
+        .array_combinations()
+        .map(|[galaxy, other]| {
+            galaxy
+                .position
+                .rows(&other.position)
+                .filter(|&row| expanded_rows.contains(&row.try_conv::<usize>().unwrap()))
+                .chain(galaxy.position.columns(&other.position).filter(|&column| {
+                    expanded_columns.contains(&column.try_conv::<usize>().unwrap())
+                }))
+                .map(|_| expansion - 1)
+                .sum::<u64>()
+                + galaxy.position.distance(&other.position)
+        })
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day11.txt"));
+
+    type Input<'input> = Vec<Vec<char>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_with(input, |c| match c {
+            '.' | '#' => Ok(c),
+            other => bail!("expected '.' or '#', found {other:?}"),
+        })
+        .and_then(|image| match image.height() {
+            0 => Err(ParseError::at_end(input, "no image rows")),
+            _ => image.rows().map(<[char]>::to_vec).collect_vec().pipe(Ok),
+        })
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        total_distance(input, 2).pipe(Ok)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        total_distance(input, 1_000_000).pipe(Ok)
+    }
+}
@@ -0,0 +1,214 @@
+use eyre::{ContextCompat, Result};
+use itertools::Itertools;
+use rayon::prelude::*;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+/// Arrangement counts for one row, see [`count_arrangements`].
+struct Table<'row> {
+    springs: &'row [char],
+    sizes: &'row [usize],
+    /// Springs from each position on that could all be damaged.
+    unbroken: Vec<usize>,
+    /// `ways[position][group]`, flattened.
+    ways: Vec<u128>,
+}
+
+impl<'row> Table<'row> {
+    fn new(springs: &'row [char], sizes: &'row [usize]) -> Self {
+        let mut unbroken = vec![0; springs.len() + 1];
+        for position in (0..springs.len()).rev() {
+            if springs[position] != '.' {
+                unbroken[position] = unbroken[position + 1] + 1;
+            }
+        }
+        let mut table = Self {
+            springs,
+            sizes,
+            unbroken,
+            ways: vec![0; (springs.len() + 1) * (sizes.len() + 1)],
+        };
+        let end = table.index(springs.len(), sizes.len());
+        table.ways[end] = 1;
+        for position in (0..springs.len()).rev() {
+            for group in 0..=sizes.len() {
+                let (damaged, operational) = table.options(position, group);
+                let index = table.index(position, group);
+                table.ways[index] = damaged.map_or(0, |(_, ways)| ways) + operational;
+            }
+        }
+        table
+    }
+
+    fn index(&self, position: usize, group: usize) -> usize {
+        position * (self.sizes.len() + 1) + group
+    }
+
+    /// Arrangements of the rest of the row when `group` starts at `position`, with where the
+    /// row continues after it, and when the spring at `position` is operational instead.
+    fn options(&self, position: usize, group: usize) -> (Option<(usize, u128)>, u128) {
+        let operational = match self.springs[position] {
+            '#' => 0,
+            _ => self.ways[self.index(position + 1, group)],
+        };
+        let damaged = self
+            .sizes
+            .get(group)
+            .filter(|&&size| {
+                size <= self.unbroken[position] && self.springs.get(position + size) != Some(&'#')
+            })
+            .map(|&size| {
+                let next = (position + size + 1).min(self.springs.len());
+                (next, self.ways[self.index(next, group + 1)])
+            });
+        (damaged, operational)
+    }
+
+    fn count(&self) -> u128 {
+        self.ways[self.index(0, 0)]
+    }
+
+    /// The `nth` arrangement in alphabetical order, `#` coming before `.`.
+    fn arrangement(&self, mut nth: u128) -> String {
+        let (mut position, mut group) = (0, 0);
+        let mut arrangement = String::with_capacity(self.springs.len());
+        while position < self.springs.len() {
+            match self.options(position, group) {
+                (Some((next, ways)), _) if nth < ways => {
+                    let size = self.sizes[group];
+                    arrangement.extend(std::iter::repeat_n('#', size));
+                    arrangement.extend(std::iter::repeat_n('.', next - position - size));
+                    (position, group) = (next, group + 1);
+                }
+                (damaged, _) => {
+                    nth -= damaged.map_or(0, |(_, ways)| ways);
+                    arrangement.push('.');
+                    position += 1;
+                }
+            }
+        }
+        arrangement
+    }
+}
+
+/// Number of ways to fill in the `?` in `springs` so its runs of `#` have the given `sizes`.
+///
+/// `ways[position][group]` counts the arrangements of `springs[position..]` holding exactly
+/// the groups `sizes[group..]`, filled in from the end of the row. Each position either
+/// stays operational, or starts the next group when the group fits there: no `.` inside it,
+/// and no `#` right after it.
+pub fn count_arrangements(springs: &[char], sizes: &[usize]) -> u128 {
+    Table::new(springs, sizes).count()
+}
+
+/// Every way to fill in a row, in alphabetical order, built one at a time from the counts.
+///
+/// Any arrangement can be built straight from its index, so skipping ahead is free even on
+/// unfolded rows with trillions of arrangements.
+pub struct Arrangements<'row> {
+    table: Table<'row>,
+    next: u128,
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next < self.table.count()).then(|| {
+            self.next += 1;
+            self.table.arrangement(self.next - 1)
+        })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.next = self.next.saturating_add(n as u128);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.table.count().saturating_sub(self.next);
+        (
+            usize::try_from(remaining).unwrap_or(usize::MAX),
+            usize::try_from(remaining).ok(),
+        )
+    }
+}
+
+/// The concrete `#` and `.` rows matching `springs` with runs of `#` the given `sizes`.
+pub fn arrangements<'row>(springs: &'row [char], sizes: &'row [usize]) -> Arrangements<'row> {
+    Arrangements {
+        table: Table::new(springs, sizes),
+        next: 0,
+    }
+}
+
+fn answer(input: &[(Vec<char>, Vec<usize>)]) -> u128 {
+    input
+        .par_iter()
+        .map(|(springs, sizes)| count_arrangements(springs, sizes))
+        .sum::<_>()
+}
+
+/// How many copies of a row part 2 unfolds it into.
+pub const UNFOLD: usize = 5;
+
+/// `copies` of the springs joined by `?`, and as many copies of the group sizes.
+pub fn unfolded(springs: &[char], sizes: &[usize], copies: usize) -> (Vec<char>, Vec<usize>) {
+    (vec![springs; copies].join(&'?'), sizes.repeat(copies))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day12.txt"));
+
+    type Input<'input> = Vec<(Vec<char>, Vec<usize>)>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (springs, counts) = line
+                    .split_once(' ')
+                    .context("no space between springs and counts")
+                    .located(input, line)?;
+                if let Some((index, other)) = springs
+                    .char_indices()
+                    .find(|(_, spring)| !matches!(spring, '.' | '#' | '?'))
+                {
+                    return Err(ParseError::at(
+                        input,
+                        &springs[index..],
+                        format!("unknown spring: {other:?}"),
+                    ));
+                }
+                counts
+                    .split(',')
+                    .map(|c| c.parse::<usize>().located(input, c))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|counts| (springs.chars().collect_vec(), counts))
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        answer(input).pipe(Ok)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        input
+            .iter()
+            .map(|(springs, sizes)| unfolded(springs, sizes, UNFOLD))
+            .collect_vec()
+            .pipe(|input| answer(&input))
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,159 @@
+use eyre::{bail, ContextCompat, Result};
+use itertools::Itertools;
+use std::ops::{Div, Mul};
+use tap::prelude::*;
+
+use crate::{
+    grid::{Grid, Position},
+    parse::ParseError,
+    Solution,
+};
+
+impl std::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone)]
+pub struct Pattern(Grid<char>);
+
+impl Pattern {
+    fn smudge_fixes(&self) -> impl Iterator<Item = Self> + '_ {
+        self.positions().map(|position| {
+            self.clone().tap_mut(|pattern| {
+                pattern.0.get_mut(position).unwrap().pipe(|current| {
+                    match *current {
+                        '.' => '#',
+                        '#' => '.',
+                        other => unreachable!("checked while parsing: {other}"),
+                    }
+                    .pipe(|new| *current = new)
+                })
+            })
+        })
+    }
+}
+
+impl Pattern {
+    fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.0.positions()
+    }
+}
+fn is_reflection_by<U, T, F>(slice: &[U], by: F) -> bool
+where
+    T: PartialEq + Eq,
+    F: Fn(&U) -> &T,
+{
+    slice
+        .iter()
+        .zip(slice.iter().rev())
+        .all(|(one, other)| by(one) == by(other))
+}
+
+fn reflections(pattern: &Pattern) -> Vec<u32> {
+    // columns are the rows of the transposed pattern
+    let transposed = pattern.0.transposed();
+    [&pattern.0, &transposed]
+        .map(|grid| {
+            [false, true]
+                .iter()
+                .map(|&flip| {
+                    grid.rows()
+                        .collect_vec()
+                        .tap_mut(|rows| {
+                            if flip {
+                                rows.reverse();
+                            }
+                        })
+                        .pipe(|rows| {
+                            (2..(rows.len()))
+                                .rev()
+                                .filter(|r| r % 2 == 0)
+                                .find_map(|offset| {
+                                    (&rows[..offset])
+                                        .pipe(|slice| {
+                                            is_reflection_by(slice, |line| line)
+                                                .then_some(slice.len().div(2))
+                                                .map(|slice_part| {
+                                                    slice_part
+                                                        + match flip {
+                                                            false => 0,
+                                                            true => rows.len() - slice.len(),
+                                                        }
+                                                })
+                                        })
+                                        .map(|v| v.try_conv::<u32>().unwrap())
+                                })
+                        })
+                })
+                .collect_vec()
+        })
+        .pipe(|[row, column]| {
+            row.into_iter()
+                .zip(column)
+                .flat_map(|(row, column)| match (row, column) {
+                    (None, None) => vec![],
+                    (None, Some(column)) => vec![column],
+                    (Some(row), None) => vec![row.mul(100)],
+                    (Some(row), Some(column)) => vec![row.mul(100), column],
+                })
+        })
+        .collect_vec()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day13.txt"));
+
+    type Input<'input> = Vec<Pattern>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .split("\n\n")
+            .map(|pattern| {
+                Grid::parse_with(pattern, |c| match c {
+                    '.' | '#' => Ok(c),
+                    other => bail!("expected '.' or '#', found {other:?}"),
+                })
+                .map(Pattern)
+                .map_err(|error| error.within(input, pattern))
+            })
+            .collect()
+    }
+
+    fn part_1(patterns: &Self::Input<'_>) -> Result<Self::Part1> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                reflections(pattern)
+                    .first()
+                    .copied()
+                    .with_context(|| format!("no reflection in \n{pattern}"))
+            })
+            .sum()
+    }
+
+    fn part_2(patterns: &Self::Input<'_>) -> Result<Self::Part2> {
+        patterns
+            .iter()
+            .map(|original| {
+                reflections(original)
+                    .first()
+                    .copied()
+                    .with_context(|| format!("no reflection in \n{original}"))
+                    .and_then(|original_res| {
+                        original
+                            .smudge_fixes()
+                            .flat_map(|pattern| reflections(&pattern))
+                            .find(|p| p != &original_res)
+                            .with_context(|| format!("should be at least one for \n{original}"))
+                    })
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,217 @@
+use eyre::{eyre, Result};
+use itertools::Itertools;
+use std::{
+    collections::HashMap,
+    iter::{once, successors},
+    ops::Sub,
+    str::FromStr,
+};
+use strum::{AsRefStr, EnumString, IntoStaticStr};
+use tap::prelude::*;
+
+use crate::{
+    grid::{Direction, Grid, Position},
+    parse::ParseError,
+    Solution,
+};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, EnumString, AsRefStr, IntoStaticStr,
+)]
+pub enum Rock {
+    #[strum(serialize = "O")]
+    Round,
+    #[strum(serialize = "#")]
+    Cube,
+}
+
+impl std::fmt::Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().pipe(|v: &str| v.fmt(f))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Input(Grid<Option<Rock>>);
+
+impl std::fmt::Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.rows().try_for_each(|line| {
+            line.iter()
+                .map(|c| c.map(<&'static str>::from).unwrap_or("."))
+                .chain(once("\n"))
+                .try_for_each(|c| c.fmt(f))
+        })
+    }
+}
+
+/// Order in which a spin cycle tilts the platform.
+const SPIN_CYCLE: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+struct Positioned<T> {
+    inner: T,
+    position: Position,
+}
+
+impl Input {
+    fn all(&self) -> impl Iterator<Item = Positioned<Option<Rock>>> + '_ {
+        self.0.iter().map(|(position, &rock)| Positioned {
+            inner: rock,
+            position,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Simulation {
+    state: Input,
+}
+
+/// Spin cycles after which the platform keeps repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Spin cycles before the first repeated state.
+    pub offset: usize,
+    pub period: usize,
+}
+
+/// Load on the north beams after spinning, and the cycle found on the way there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spun {
+    pub load: usize,
+    /// `None` when the target was reached before any state repeated.
+    pub cycle: Option<Cycle>,
+}
+
+impl std::fmt::Display for Spun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.cycle {
+            Some(Cycle { offset, period }) => write!(
+                f,
+                "{} (repeats every {period} spin cycles after {offset})",
+                self.load
+            ),
+            None => self.load.fmt(f),
+        }
+    }
+}
+
+impl Simulation {
+    pub fn new(state: Input) -> Self {
+        Self { state }
+    }
+
+    /// Rolls every round rock as far as it goes towards `direction`.
+    ///
+    /// Rocks closest to that edge move first, so each one stops against its final neighbour.
+    fn tilt(&mut self, direction: Direction) -> Result<()> {
+        let grid = &mut self.state.0;
+        grid.positions()
+            .filter(|&position| grid.get(position) == Some(&Some(Rock::Round)))
+            .sorted_by_key(|position| match direction {
+                Direction::North => position.row as isize,
+                Direction::South => -(position.row as isize),
+                Direction::West => position.column as isize,
+                Direction::East => -(position.column as isize),
+            })
+            .collect_vec()
+            .into_iter()
+            .try_for_each(|position| {
+                successors(Some(position), |&position| {
+                    grid.neighbour(position, direction)
+                        .filter(|&neighbour| grid.get(neighbour) == Some(&None))
+                })
+                .last()
+                .filter(|&destination| destination != position)
+                .map_or(Ok(()), |destination| grid.swap(position, destination))
+            })
+    }
+
+    fn spin_cycle(&mut self) -> Result<()> {
+        SPIN_CYCLE
+            .iter()
+            .try_for_each(|&direction| self.tilt(direction))
+    }
+
+    /// Spins the platform `cycles` times. Every state is remembered, so once one repeats the
+    /// remaining spin cycles are skipped over a whole number of periods.
+    pub fn spin(&self, cycles: usize) -> Result<Spun> {
+        let mut seen = HashMap::from([(self.clone(), 0)]);
+        let mut history = vec![self.clone()];
+        let mut current = self.clone();
+        for spun in 1..=cycles {
+            current.spin_cycle()?;
+            if let Some(&offset) = seen.get(&current) {
+                let period = spun - offset;
+                return Ok(Spun {
+                    load: total_load(&history[offset + (cycles - offset) % period]),
+                    cycle: Some(Cycle { offset, period }),
+                });
+            }
+            seen.insert(current.clone(), spun);
+            history.push(current.clone());
+        }
+        Ok(Spun {
+            load: total_load(&current),
+            cycle: None,
+        })
+    }
+}
+
+impl std::fmt::Display for Simulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+fn total_load(simulation: &Simulation) -> usize {
+    simulation
+        .state
+        .all()
+        .filter_map(|s| match s {
+            Positioned {
+                inner: Some(Rock::Round),
+                position,
+            } => simulation.state.0.height().sub(position.row).pipe(Some),
+            _ => None,
+        })
+        .sum::<usize>()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day14.txt"));
+
+    type Input<'input> = Input;
+    type Part1 = usize;
+    type Part2 = Spun;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_with(input, |c| match c {
+            '.' => Ok(None),
+            rock => c
+                .encode_utf8(&mut [0; 4])
+                .pipe(|c| Rock::from_str(c))
+                .map(Some)
+                .map_err(|_| eyre!("unexpected rock: {rock:?}")),
+        })
+        .map(Input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut simulation = Simulation::new(input.clone());
+        simulation.tilt(Direction::North)?;
+        Ok(total_load(&simulation))
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        Simulation::new(input.clone()).spin(1_000_000_000)
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Add, Mul},
+};
+
+use eyre::{ContextCompat, Result, WrapErr};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct HashState(usize);
+
+pub fn ascii_code(c: char) -> u8 {
+    c.try_conv().expect("bad char")
+}
+
+impl HashState {
+    fn feed(&mut self, c: char) {
+        self.0 = self
+            .0
+            .add(ascii_code(c).conv::<usize>())
+            .pipe(|new| new.mul(17).pipe(|new| new % 256))
+    }
+}
+
+pub fn hash(sequence: &str) -> usize {
+    sequence
+        .chars()
+        .fold(HashState::default(), |acc, next| {
+            acc.tap_mut(|acc| acc.feed(next))
+        })
+        .pipe(|HashState(state)| state)
+}
+
+#[derive(Debug)]
+pub enum Operation<'input> {
+    Remove(&'input str),
+    Replace(LensEntry<'input>),
+}
+
+#[derive(Clone, Copy)]
+pub struct LensEntry<'input> {
+    label: &'input str,
+    length: u8,
+}
+
+impl<'a> std::fmt::Debug for LensEntry<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pipe(|Self { label, length }| write!(f, "[{label} {length}]"))
+    }
+}
+
+fn remove_by<T, F>(collection: &mut Vec<T>, key: F) -> Option<T>
+where
+    F: (Fn(&T) -> bool),
+{
+    match collection.iter().find_position(|e| key(e)) {
+        Some((index, _)) => collection.remove(index).pipe(Some),
+        None => None,
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day15.txt"));
+
+    type Input<'input> = Vec<(&'input str, (usize, Operation<'input>))>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|operation| {
+                if let Some((index, other)) = operation.char_indices().find(|(_, c)| !c.is_ascii())
+                {
+                    return Err(ParseError::at(
+                        input,
+                        &operation[index..],
+                        format!("not an ascii character: {other:?}"),
+                    ));
+                }
+                match operation.ends_with('-') {
+                    true => (&operation[..(operation.len() - 1)]).pipe(|label| {
+                        Operation::Remove(label)
+                            .pipe(|parsed| (hash(label), parsed))
+                            .pipe(Ok)
+                    }),
+                    false => operation
+                        .split_once('=')
+                        .context("expected '=' or a trailing '-'")
+                        .located(input, operation)
+                        .and_then(|(label, focal)| {
+                            focal
+                                .parse::<u8>()
+                                .wrap_err("bad focal length")
+                                .located(input, focal)
+                                .map(|length| Operation::Replace(LensEntry { label, length }))
+                                .map(|parsed| (hash(label), parsed))
+                        }),
+                }
+                .map(|parsed| (operation, parsed))
+            })
+            .collect()
+    }
+
+    fn part_1(operations: &Self::Input<'_>) -> Result<Self::Part1> {
+        operations
+            .iter()
+            .map(|(operation, (_, _))| hash(operation))
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+
+    fn part_2(operations: &Self::Input<'_>) -> Result<Self::Part2> {
+        operations
+            .iter()
+            .fold(
+                BTreeMap::<usize, Vec<LensEntry>>::default(),
+                |acc, (_raw, (hash, operation))| {
+                    acc.tap_mut(|acc| {
+                        acc.entry(*hash)
+                            .or_default()
+                            .tap_mut(|entry| match operation {
+                                Operation::Remove(label) => {
+                                    remove_by(entry, |k| k.label.eq(*label));
+                                }
+                                Operation::Replace(to @ LensEntry { label, length }) => entry
+                                    .iter_mut()
+                                    .find(|l| l.label.eq(*label))
+                                    .map(|found| found.length = *length)
+                                    .unwrap_or_else(|| {
+                                        entry.push(*to);
+                                    }),
+                            });
+                    })
+                },
+            )
+            .iter()
+            .flat_map(|(box_idx, b)| {
+                b.iter().copied().enumerate().map(|(i, v)| (i + 1, v)).map(
+                    move |(slot_idx, LensEntry { label: _, length })| {
+                        box_idx.add(1).mul(slot_idx.mul((length).conv::<usize>()))
+                    },
+                )
+            })
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use eyre::{bail, ContextCompat, Result};
+use tap::prelude::*;
+
+use crate::{
+    grid::{Direction, Grid, Position},
+    parse::ParseError,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    /// `.` lets the beam pass through.
+    Empty,
+    /// `/` reflects the beam by 90 degrees.
+    ForwardMirror,
+    /// `\` reflects the beam by 90 degrees.
+    BackwardMirror,
+    /// `|` splits a horizontal beam into north and south.
+    VerticalSplitter,
+    /// `-` splits a vertical beam into east and west.
+    HorizontalSplitter,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = eyre::Report;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '/' => Ok(Self::ForwardMirror),
+            '\\' => Ok(Self::BackwardMirror),
+            '|' => Ok(Self::VerticalSplitter),
+            '-' => Ok(Self::HorizontalSplitter),
+            other => bail!("unexpected tile: '{other}'"),
+        }
+    }
+}
+
+impl Tile {
+    /// Directions the beam leaves this tile in, after entering it heading `heading`.
+    fn outgoing(self, heading: Direction) -> impl Iterator<Item = Direction> {
+        use Direction::*;
+        match (self, heading) {
+            (Self::Empty, _)
+            | (Self::VerticalSplitter, North | South)
+            | (Self::HorizontalSplitter, East | West) => [Some(heading), None],
+            (Self::ForwardMirror, _) => match heading {
+                North => East,
+                East => North,
+                South => West,
+                West => South,
+            }
+            .pipe(|reflected| [Some(reflected), None]),
+            (Self::BackwardMirror, _) => match heading {
+                North => West,
+                West => North,
+                South => East,
+                East => South,
+            }
+            .pipe(|reflected| [Some(reflected), None]),
+            (Self::VerticalSplitter, East | West) => [Some(North), Some(South)],
+            (Self::HorizontalSplitter, North | South) => [Some(East), Some(West)],
+        }
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Number of tiles the beam passes through when it enters at `start` heading `heading`.
+///
+/// Beams are tracked by (position, heading), so loops between splitters terminate.
+pub fn energized(grid: &Grid<Tile>, start: Position, heading: Direction) -> usize {
+    let mut visited = HashSet::new();
+    let mut beams = vec![(start, heading)];
+    while let Some((position, heading)) = beams.pop() {
+        let Some(tile) = grid.get(position) else {
+            continue;
+        };
+        if !visited.insert((position, heading)) {
+            continue;
+        }
+        beams.extend(tile.outgoing(heading).filter_map(|outgoing| {
+            grid.neighbour(position, outgoing)
+                .map(|next| (next, outgoing))
+        }));
+    }
+    visited
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Every tile on the edge of the grid, paired with the heading that enters the grid from it.
+fn entry_points(grid: &Grid<Tile>) -> impl Iterator<Item = (Position, Direction)> + '_ {
+    let (width, height) = (grid.width(), grid.height());
+    (0..width)
+        .flat_map(move |column| {
+            [
+                (Position::new(0, column), Direction::South),
+                (Position::new(height - 1, column), Direction::North),
+            ]
+        })
+        .chain((0..height).flat_map(move |row| {
+            [
+                (Position::new(row, 0), Direction::East),
+                (Position::new(row, width - 1), Direction::West),
+            ]
+        }))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Grid<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        energized(grid, Position::new(0, 0), Direction::East).pipe(Ok)
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        entry_points(grid)
+            .map(|(start, heading)| energized(grid, start, heading))
+            .max()
+            .context("empty grid")
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use eyre::{ContextCompat, Result};
+
+use crate::{
+    grid::{Direction, Grid, Position, Turn},
+    parse::ParseError,
+    Solution,
+};
+
+/// How many tiles a crucible has to and may move in a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    /// Tiles moved straight before the crucible can turn or stop.
+    pub min_run: usize,
+    /// Tiles moved straight after which the crucible has to turn.
+    pub max_run: usize,
+}
+
+pub const CRUCIBLE: Crucible = Crucible {
+    min_run: 1,
+    max_run: 3,
+};
+
+pub const ULTRA_CRUCIBLE: Crucible = Crucible {
+    min_run: 4,
+    max_run: 10,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct State {
+    position: Position,
+    heading: Direction,
+    /// Tiles moved in `heading` so far.
+    run: usize,
+}
+
+impl Crucible {
+    /// States reachable in one move. A crucible never reverses, and `moved` has no U-turn.
+    fn moves(self, State { heading, run, .. }: State) -> impl Iterator<Item = (Direction, usize)> {
+        [Turn::Straight, Turn::Left, Turn::Right]
+            .into_iter()
+            .filter_map(move |turn| match turn {
+                Turn::Straight => (run < self.max_run).then_some((heading, run + 1)),
+                Turn::Left | Turn::Right => {
+                    (run >= self.min_run).then_some((heading.moved(turn), 1))
+                }
+            })
+    }
+}
+
+/// Least heat lost moving a `crucible` from the top left to the bottom right tile.
+pub fn least_heat_loss(grid: &Grid<u32>, crucible: Crucible) -> Option<u32> {
+    let start = Position::new(0, 0);
+    let end = Position::new(grid.height().checked_sub(1)?, grid.width().checked_sub(1)?);
+    if start == end {
+        return Some(0);
+    }
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::from([Direction::East, Direction::South].map(|heading| {
+        Reverse((
+            0,
+            State {
+                position: start,
+                heading,
+                run: 0,
+            },
+        ))
+    }));
+    while let Some(Reverse((heat_loss, state))) = queue.pop() {
+        if state.position == end && state.run >= crucible.min_run {
+            return Some(heat_loss);
+        }
+        if best.get(&state).is_some_and(|&best| best < heat_loss) {
+            continue;
+        }
+        for (heading, run) in crucible.moves(state) {
+            let Some(position) = grid.neighbour(state.position, heading) else {
+                continue;
+            };
+            let next = State {
+                position,
+                heading,
+                run,
+            };
+            let heat_loss = heat_loss + grid.get(position).copied().unwrap_or_default();
+            if best.get(&next).is_none_or(|&best| heat_loss < best) {
+                best.insert(next, heat_loss);
+                queue.push(Reverse((heat_loss, next)));
+            }
+        }
+    }
+    None
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Grid<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .with_context(|| format!("expected a heat loss digit, found {c:?}"))
+        })
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        least_heat_loss(grid, CRUCIBLE).context("no path to the factory")
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        least_heat_loss(grid, ULTRA_CRUCIBLE).context("no path to the factory")
+    }
+}
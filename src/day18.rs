@@ -0,0 +1,155 @@
+use eyre::{bail, ensure, ContextCompat, Result, WrapErr};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    grid::Direction,
+    math::interior_points,
+    parse::{Located, ParseError},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub direction: Direction,
+    pub distance: u32,
+}
+
+/// One line of the dig plan, read both ways.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    /// `R 6`, the direction and distance written out.
+    pub plain: Step,
+    /// `(#70c710)`, five hex digits of distance followed by the direction as `0..=3`.
+    pub hex: Step,
+}
+
+fn direction(letter: &str) -> Result<Direction> {
+    match letter {
+        "U" => Ok(Direction::North),
+        "D" => Ok(Direction::South),
+        "L" => Ok(Direction::West),
+        "R" => Ok(Direction::East),
+        other => bail!("unknown direction: {other:?}"),
+    }
+}
+
+fn hex_step(color: &str) -> Result<Step> {
+    let hex = color
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6)
+        .with_context(|| format!("expected (#rrggbb), found {color:?}"))?;
+    let distance = u32::from_str_radix(&hex[..5], 16).wrap_err("bad hex distance")?;
+    let direction = match &hex[5..] {
+        "0" => Direction::East,
+        "1" => Direction::South,
+        "2" => Direction::West,
+        "3" => Direction::North,
+        other => bail!("unknown hex direction: {other:?}"),
+    };
+    Ok(Step {
+        direction,
+        distance,
+    })
+}
+
+fn offset(direction: Direction) -> (i128, i128) {
+    match direction {
+        Direction::North => (-1, 0),
+        Direction::South => (1, 0),
+        Direction::West => (0, -1),
+        Direction::East => (0, 1),
+    }
+}
+
+/// Cubic meters of lava held by the lagoon dug along `steps`: the cubes inside the polygon
+/// through the trench's centre line, plus one cube per meter of trench.
+pub fn lagoon_volume(steps: &[Step]) -> Result<i64> {
+    ensure!(!steps.is_empty(), "empty dig plan");
+    steps
+        .iter()
+        .circular_tuple_windows()
+        .try_for_each(|(step, next)| {
+            step.direction
+                .turn_to(next.direction)
+                .map(drop)
+                .with_context(|| format!("trench doubles back from {step:?} to {next:?}"))
+        })?;
+    let corners = steps
+        .iter()
+        .scan((0_i128, 0_i128), |(row, column), step| {
+            let (rows, columns) = offset(step.direction);
+            *row += rows * i128::from(step.distance);
+            *column += columns * i128::from(step.distance);
+            Some((*row, *column))
+        })
+        .collect_vec();
+    ensure!(
+        corners.last() == Some(&(0, 0)),
+        "trench ends at {:?} instead of the start",
+        corners.last()
+    );
+    let boundary = steps
+        .iter()
+        .map(|step| i128::from(step.distance))
+        .sum::<i128>();
+    (interior_points(&corners, boundary) + boundary)
+        .try_conv::<i64>()
+        .wrap_err("lagoon too large")
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let [letter, distance, color] = line
+                    .split_whitespace()
+                    .collect_vec()
+                    .try_conv::<[&str; 3]>()
+                    .map_err(|tokens| {
+                        ParseError::at(
+                            input,
+                            line,
+                            format!("expected 3 parts, found {}", tokens.len()),
+                        )
+                    })?;
+                Ok(Instruction {
+                    plain: Step {
+                        direction: direction(letter).located(input, letter)?,
+                        distance: distance
+                            .parse()
+                            .wrap_err("expected a non-negative distance")
+                            .located(input, distance)?,
+                    },
+                    hex: hex_step(color).located(input, color)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(plan: &Self::Input<'_>) -> Result<Self::Part1> {
+        plan.iter()
+            .map(|instruction| instruction.plain)
+            .collect_vec()
+            .pipe(|steps| lagoon_volume(&steps))
+    }
+
+    fn part_2(plan: &Self::Input<'_>) -> Result<Self::Part2> {
+        plan.iter()
+            .map(|instruction| instruction.hex)
+            .collect_vec()
+            .pipe(|steps| lagoon_volume(&steps))
+    }
+}
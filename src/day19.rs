@@ -0,0 +1,274 @@
+use std::{collections::HashMap, str::FromStr};
+
+use eyre::{bail, eyre, ContextCompat, Result};
+use itertools::Itertools;
+use strum::EnumString;
+use tap::prelude::*;
+
+use crate::{
+    interval::{Interval, IntervalSet},
+    parse::{Located, ParseError},
+    Solution,
+};
+
+/// Every rating a part can have in a category.
+pub const RATINGS: Interval = Interval::new(1, 4001);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    Greater,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Condition {
+    pub category: Category,
+    pub comparison: Comparison,
+    pub value: i64,
+}
+
+impl Condition {
+    /// Ratings the condition holds for.
+    fn matching(self) -> IntervalSet {
+        match self.comparison {
+            Comparison::Less => Interval::new(i64::MIN, self.value),
+            Comparison::Greater => Interval::new(self.value.saturating_add(1), i64::MAX),
+        }
+        .into()
+    }
+
+    fn holds(self, part: &Part) -> bool {
+        self.matching().contains(part[self.category as usize])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target<'input> {
+    Accept,
+    Reject,
+    Workflow(&'input str),
+}
+
+impl<'input> From<&'input str> for Target<'input> {
+    fn from(target: &'input str) -> Self {
+        match target {
+            "A" => Self::Accept,
+            "R" => Self::Reject,
+            workflow => Self::Workflow(workflow),
+        }
+    }
+}
+
+/// Sends parts on to `target`, if they match the condition. Rules without one match anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule<'input> {
+    pub condition: Option<Condition>,
+    pub target: Target<'input>,
+}
+
+/// Ratings in x, m, a and s order.
+pub type Part = [i64; 4];
+
+/// Every combination of ratings in the sets, one per category.
+pub type Hypercube = [IntervalSet; 4];
+
+#[derive(Debug, Clone)]
+pub struct System<'input> {
+    pub workflows: HashMap<&'input str, Vec<Rule<'input>>>,
+    pub parts: Vec<Part>,
+}
+
+impl<'input> System<'input> {
+    fn workflow(&self, name: &str) -> Result<&[Rule<'input>]> {
+        self.workflows
+            .get(name)
+            .map(Vec::as_slice)
+            .with_context(|| format!("no workflow named {name:?}"))
+    }
+
+    /// Runs `part` through the workflows starting at `in`.
+    pub fn accepts(&self, part: &Part) -> Result<bool> {
+        let mut name = "in";
+        // every workflow visited twice means the part is going around in circles
+        for _ in 0..=self.workflows.len() {
+            let target = self
+                .workflow(name)?
+                .iter()
+                .find(|rule| rule.condition.is_none_or(|condition| condition.holds(part)))
+                .with_context(|| format!("no rule in {name:?} matches {part:?}"))?
+                .target;
+            match target {
+                Target::Accept => return Ok(true),
+                Target::Reject => return Ok(false),
+                Target::Workflow(next) => name = next,
+            }
+        }
+        bail!("workflows loop for {part:?}")
+    }
+
+    /// Number of rating combinations in `cube` that end up accepted when sent to `target`.
+    ///
+    /// Each rule splits the cube in the part matching its condition, which follows the rule,
+    /// and the rest, which moves on to the next rule.
+    pub fn accepted_combinations(&self, target: Target<'_>, cube: Hypercube) -> Result<i64> {
+        self.count_accepted(target, cube, &mut vec![])
+    }
+
+    fn count_accepted<'a>(
+        &'a self,
+        target: Target<'a>,
+        cube: Hypercube,
+        path: &mut Vec<&'a str>,
+    ) -> Result<i64> {
+        let name = match target {
+            Target::Accept => return cube.iter().map(IntervalSet::len).product::<i64>().pipe(Ok),
+            Target::Reject => return Ok(0),
+            Target::Workflow(name) => name,
+        };
+        if path.contains(&name) {
+            bail!("workflows loop: {} -> {name}", path.iter().join(" -> "));
+        }
+        path.push(name);
+        let mut remaining = Some(cube);
+        let mut accepted = 0;
+        for rule in self.workflow(name)? {
+            let Some(cube) = remaining.take() else {
+                break;
+            };
+            let (matching, rest) = match rule.condition {
+                None => (cube, None),
+                Some(condition) => {
+                    let index = condition.category as usize;
+                    let ratings = &cube[index];
+                    let matching = condition.matching();
+                    (
+                        cube.clone()
+                            .tap_mut(|cube| cube[index] = ratings.intersection(&matching)),
+                        cube.clone()
+                            .tap_mut(|cube| cube[index] = ratings.difference(&matching))
+                            .pipe(Some),
+                    )
+                }
+            };
+            if matching.iter().all(|ratings| !ratings.is_empty()) {
+                accepted += self.count_accepted(rule.target, matching, path)?;
+            }
+            remaining = rest.filter(|rest| rest.iter().all(|ratings| !ratings.is_empty()));
+        }
+        path.pop();
+        Ok(accepted)
+    }
+}
+
+fn rule(rule: &str) -> Result<Rule<'_>> {
+    let Some((condition, target)) = rule.split_once(':') else {
+        return Ok(Rule {
+            condition: None,
+            target: rule.into(),
+        });
+    };
+    let split = condition
+        .find(['<', '>'])
+        .with_context(|| format!("no '<' or '>' in {condition:?}"))?;
+    let (category, value) = (&condition[..split], &condition[split + 1..]);
+    Ok(Rule {
+        condition: Some(Condition {
+            category: Category::from_str(category)
+                .map_err(|_| eyre!("unknown category: {category:?}"))?,
+            comparison: match &condition[split..=split] {
+                "<" => Comparison::Less,
+                _ => Comparison::Greater,
+            },
+            value: value.parse().map_err(|_| eyre!("bad rating: {value:?}"))?,
+        }),
+        target: target.into(),
+    })
+}
+
+fn part(part: &str) -> Result<Part> {
+    let mut rated = [None; 4];
+    part.strip_prefix('{')
+        .and_then(|part| part.strip_suffix('}'))
+        .with_context(|| format!("expected {{x=..,m=..,a=..,s=..}}, found {part:?}"))?
+        .split(',')
+        .try_for_each(|rating| {
+            rating
+                .split_once('=')
+                .and_then(|(category, value)| {
+                    Category::from_str(category).ok().zip(value.parse().ok())
+                })
+                .map(|(category, value)| rated[category as usize] = Some(value))
+                .with_context(|| format!("bad rating: {rating:?}"))
+        })?;
+    match rated {
+        [Some(x), Some(m), Some(a), Some(s)] => Ok([x, m, a, s]),
+        _ => Err(eyre!("missing a rating in {part:?}")),
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = System<'input>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (workflows, parts) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "no empty line after the workflows"))?;
+        let workflows = workflows
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (name, rules) = line
+                    .strip_suffix('}')
+                    .and_then(|line| line.split_once('{'))
+                    .context("expected name{rules}")
+                    .located(input, line)?;
+                rules
+                    .split(',')
+                    .map(|rule| self::rule(rule).located(input, rule))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|rules| (name, rules))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        parts
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| part(line).located(input, line))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|parts| System { workflows, parts })
+    }
+
+    fn part_1(system: &Self::Input<'_>) -> Result<Self::Part1> {
+        system
+            .parts
+            .iter()
+            .filter_map(|part| match system.accepts(part) {
+                Ok(true) => Some(Ok(part.iter().sum::<i64>())),
+                Ok(false) => None,
+                Err(error) => Some(Err(error)),
+            })
+            .sum()
+    }
+
+    fn part_2(system: &Self::Input<'_>) -> Result<Self::Part2> {
+        system.accepted_combinations(
+            Target::Workflow("in"),
+            std::array::from_fn(|_| RATINGS.into()),
+        )
+    }
+}
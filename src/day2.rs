@@ -0,0 +1,168 @@
+use eyre::{Context, ContextCompat, Result};
+use std::collections::BTreeMap;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Clone, Ord)]
+pub struct Color(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub struct Subgame(pub BTreeMap<Color, usize>);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub struct Entry {
+    pub color: Color,
+    pub count: usize,
+}
+
+impl Subgame {
+    pub fn entries(self) -> Vec<Entry> {
+        self.0
+            .into_iter()
+            .map(|(color, count)| Entry { color, count })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Clone)]
+pub struct Game {
+    pub id: usize,
+    pub subgames: Vec<Subgame>,
+}
+
+fn filter_by_subgame_count<'game, 'color: 'game>(
+    game: &'game Game,
+    lookup_color: &'color Color,
+    max_count: usize,
+) -> Option<&'game Game> {
+    match game
+        .subgames
+        .iter()
+        .flat_map(|subgame| {
+            subgame
+                .0
+                .iter()
+                .filter_map(|(color, &count)| color.eq(lookup_color).then_some(count))
+        })
+        .find(|count| count.gt(&max_count))
+    {
+        Some(_count) => None,
+        None => Some(game),
+    }
+}
+
+fn fewest_cubes<'game, 'color: 'game>(game: &'game Game, lookup_color: &'color Color) -> usize {
+    game.subgames
+        .iter()
+        .flat_map(|subgame| subgame.clone().entries())
+        .filter_map(|e| (&e.color == lookup_color).then_some(e.count))
+        .max()
+        .unwrap_or_default()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day2.txt"));
+
+    type Input<'input> = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|line| {
+                line.split_once(": ")
+                    .context("no :")
+                    .and_then(|(header, subgames)| {
+                        header
+                            .split_once(' ')
+                            .context("no game id")
+                            .and_then(|(_game, id)| -> Result<usize> {
+                                id.parse().context("bad id")
+                            })
+                            .and_then(|id| {
+                                subgames
+                                    .split("; ")
+                                    .map(|colors| {
+                                        colors
+                                            .split(", ")
+                                            .map(|color| {
+                                                color
+                                                    .split_once(' ')
+                                                    .context("bad color")
+                                                    .and_then(|(count, color)| {
+                                                        count.parse().context("Bad count").map(
+                                                            |count| Entry {
+                                                                color: Color(color.to_owned()),
+                                                                count,
+                                                            },
+                                                        )
+                                                    })
+                                                    .with_context(|| {
+                                                        format!("parsing color: '{color}'")
+                                                    })
+                                            })
+                                            .collect::<Result<Vec<_>>>()
+                                            .context("reading entries")
+                                            .map(|entries| {
+                                                Subgame(
+                                                    entries
+                                                        .into_iter()
+                                                        .map(|Entry { color, count }| {
+                                                            (color, count)
+                                                        })
+                                                        .collect(),
+                                                )
+                                            })
+                                            .with_context(|| format!("parsing colors: '{colors}'"))
+                                    })
+                                    .collect::<Result<Vec<_>>>()
+                                    .context("bad entries")
+                                    .map(|subgames| Game { id, subgames })
+                            })
+                    })
+                    .located(input, line)
+            })
+            .collect()
+    }
+
+    fn part_1(games: &Self::Input<'_>) -> Result<Self::Part1> {
+        let red = Color("red".to_owned());
+        let blue = Color("blue".to_owned());
+        let green = Color("green".to_owned());
+
+        games
+            .iter()
+            .filter_map(|game| filter_by_subgame_count(game, &red, 12))
+            .filter_map(|game| filter_by_subgame_count(game, &green, 13))
+            .filter_map(|game| filter_by_subgame_count(game, &blue, 14))
+            .map(|game| game.id)
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+
+    fn part_2(games: &Self::Input<'_>) -> Result<Self::Part2> {
+        let red = Color("red".to_owned());
+        let blue = Color("blue".to_owned());
+        let green = Color("green".to_owned());
+
+        games
+            .iter()
+            .map(|game| {
+                [&red, &blue, &green]
+                    .into_iter()
+                    .map(|color| fewest_cubes(game, color))
+                    .product::<usize>()
+            })
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use eyre::{bail, ensure, ContextCompat, Result};
+use itertools::Itertools;
+
+use crate::{
+    math::lcm,
+    parse::{Located, ParseError},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind<'input> {
+    /// Repeats every pulse to all its outputs.
+    Broadcaster,
+    /// `%`, ignores high pulses and flips on every low one, sending high when it turns on.
+    FlipFlop { on: bool },
+    /// `&`, remembers the last pulse from each input and sends low only once all were high.
+    Conjunction {
+        memory: BTreeMap<&'input str, Pulse>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module<'input> {
+    pub kind: Kind<'input>,
+    pub outputs: Vec<&'input str>,
+}
+
+impl<'input> Module<'input> {
+    /// What the module sends to all its outputs after receiving `pulse` from `from`.
+    fn receive(&mut self, from: &'input str, pulse: Pulse) -> Option<Pulse> {
+        match &mut self.kind {
+            Kind::Broadcaster => Some(pulse),
+            Kind::FlipFlop { .. } if pulse == Pulse::High => None,
+            Kind::FlipFlop { on } => {
+                *on = !*on;
+                Some(if *on { Pulse::High } else { Pulse::Low })
+            }
+            Kind::Conjunction { memory } => {
+                memory.insert(from, pulse);
+                match memory.values().all(|&remembered| remembered == Pulse::High) {
+                    true => Some(Pulse::Low),
+                    false => Some(Pulse::High),
+                }
+            }
+        }
+    }
+}
+
+pub const BROADCASTER: &str = "broadcaster";
+const BUTTON: &str = "button";
+
+/// A single pulse on its way from one module to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sent<'input> {
+    pub from: &'input str,
+    pub pulse: Pulse,
+    pub to: &'input str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Circuit<'input> {
+    pub modules: HashMap<&'input str, Module<'input>>,
+}
+
+impl<'input> Circuit<'input> {
+    /// Pushes the button once, handing every pulse to `observe` in the order it is delivered.
+    pub fn press(&mut self, mut observe: impl FnMut(Sent<'input>)) {
+        let mut queue = VecDeque::from([Sent {
+            from: BUTTON,
+            pulse: Pulse::Low,
+            to: BROADCASTER,
+        }]);
+        while let Some(sent) = queue.pop_front() {
+            observe(sent);
+            let Some(module) = self.modules.get_mut(sent.to) else {
+                continue;
+            };
+            if let Some(pulse) = module.receive(sent.from, sent.pulse) {
+                queue.extend(module.outputs.iter().map(|&to| Sent {
+                    from: sent.to,
+                    pulse,
+                    to,
+                }));
+            }
+        }
+    }
+
+    /// Modules sending pulses to `module`.
+    pub fn inputs<'a>(&'a self, module: &'a str) -> impl Iterator<Item = &'input str> + 'a {
+        self.modules
+            .iter()
+            .filter(move |(_, Module { outputs, .. })| outputs.contains(&module))
+            .map(|(&name, _)| name)
+    }
+
+    /// Button presses until `module` first receives a low pulse.
+    ///
+    /// The module has to be fed by a single conjunction, whose inputs each send it a high pulse
+    /// on a fixed cycle starting at the first press. The conjunction sends low in the press all
+    /// of them line up, which is the least common multiple of their cycles.
+    pub fn presses_until_low(&self, module: &str) -> Result<u64> {
+        let feeder = self
+            .inputs(module)
+            .exactly_one()
+            .map_err(|inputs| eyre::eyre!("{module} is fed by {:?}", inputs.collect_vec()))?;
+        ensure!(
+            matches!(self.modules[feeder].kind, Kind::Conjunction { .. }),
+            "{feeder} feeding {module} is not a conjunction"
+        );
+        let inputs = self.inputs(feeder).collect_vec();
+        let mut high_at = inputs
+            .iter()
+            .map(|&input| (input, vec![]))
+            .collect::<HashMap<_, Vec<u64>>>();
+        let mut circuit = self.clone();
+        // two high pulses from every input pin down their cycles
+        for press in 1..=1_000_000 {
+            circuit.press(|sent| {
+                if sent.to == feeder && sent.pulse == Pulse::High {
+                    if let Some(presses) = high_at.get_mut(sent.from) {
+                        presses.push(press);
+                    }
+                }
+            });
+            if high_at.values().all(|presses| presses.len() >= 2) {
+                return high_at
+                    .into_iter()
+                    .map(|(input, presses)| {
+                        let (first, second) = (presses[0], presses[1]);
+                        ensure!(
+                            second - first == first,
+                            "{input} sends high at {first} and {second}, not a cycle from the start"
+                        );
+                        Ok(first)
+                    })
+                    .fold_ok(1, lcm);
+            }
+        }
+        bail!("no cycles found feeding {feeder}")
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Circuit<'input>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut modules = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (name, outputs) = line
+                    .split_once(" -> ")
+                    .context("no ' -> ' before the outputs")
+                    .located(input, line)?;
+                let (kind, name) = match name.split_at_checked(1) {
+                    Some(("%", name)) => (Kind::FlipFlop { on: false }, name),
+                    Some(("&", name)) => (
+                        Kind::Conjunction {
+                            memory: BTreeMap::new(),
+                        },
+                        name,
+                    ),
+                    _ if name == BROADCASTER => (Kind::Broadcaster, name),
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            name,
+                            format!("expected %, & or {BROADCASTER}, found {name:?}"),
+                        ))
+                    }
+                };
+                Ok((
+                    name,
+                    Module {
+                        kind,
+                        outputs: outputs.split(", ").collect(),
+                    },
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let connections = modules
+            .iter()
+            .flat_map(|(&name, module)| module.outputs.iter().map(move |&output| (name, output)))
+            .collect_vec();
+        connections.into_iter().for_each(|(from, to)| {
+            if let Some(Module {
+                kind: Kind::Conjunction { memory },
+                ..
+            }) = modules.get_mut(to)
+            {
+                memory.insert(from, Pulse::Low);
+            }
+        });
+        Ok(Circuit { modules })
+    }
+
+    fn part_1(circuit: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut circuit = circuit.clone();
+        let (mut low, mut high) = (0, 0);
+        (0..1000).for_each(|_| {
+            circuit.press(|sent| match sent.pulse {
+                Pulse::Low => low += 1,
+                Pulse::High => high += 1,
+            })
+        });
+        Ok(low * high)
+    }
+
+    fn part_2(circuit: &Self::Input<'_>) -> Result<Self::Part2> {
+        circuit.presses_until_low("rx")
+    }
+}
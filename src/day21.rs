@@ -0,0 +1,211 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use eyre::{bail, ensure, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    day9::differences,
+    grid::{Direction, Grid, Position},
+    parse::ParseError,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Plot,
+    Rock,
+    /// The plot the elf starts on.
+    Start,
+}
+
+/// Which copy of the map a position is in once it repeats in every direction, `(0, 0)` being
+/// the original.
+type Repeat = (i64, i64);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garden {
+    pub grid: Grid<Tile>,
+    pub start: Position,
+}
+
+impl Garden {
+    fn is_plot(&self, position: Position) -> bool {
+        self.grid
+            .get(position)
+            .is_some_and(|&tile| tile != Tile::Rock)
+    }
+
+    /// Steps to every plot reachable from the start, with the map ending at its edges.
+    pub fn distances(&self) -> HashMap<Position, usize> {
+        let mut distances = HashMap::from([(self.start, 0)]);
+        let mut queue = VecDeque::from([self.start]);
+        while let Some(position) = queue.pop_front() {
+            let distance = distances[&position] + 1;
+            for (next, _) in self.grid.neighbours(position) {
+                if self.is_plot(next) && !distances.contains_key(&next) {
+                    distances.insert(next, distance);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Plots the elf can end up on in exactly `steps` steps.
+    ///
+    /// Stepping back and forth wastes two steps at a time, so that is every plot no further
+    /// than `steps` away with the same parity.
+    pub fn reachable(&self, steps: usize) -> usize {
+        self.distances()
+            .into_values()
+            .filter(|&distance| distance <= steps && distance % 2 == steps % 2)
+            .count()
+    }
+
+    /// The adjacent position when the map repeats, stepping into the next copy over at the edges.
+    fn neighbour_repeated(
+        &self,
+        (repeat, position): (Repeat, Position),
+        direction: Direction,
+    ) -> (Repeat, Position) {
+        if let Some(position) = self.grid.neighbour(position, direction) {
+            return (repeat, position);
+        }
+        let ((rows, columns), Position { row, column }) = (repeat, position);
+        let (last_row, last_column) = (self.grid.height() - 1, self.grid.width() - 1);
+        match direction {
+            Direction::North => ((rows - 1, columns), Position::new(last_row, column)),
+            Direction::South => ((rows + 1, columns), Position::new(0, column)),
+            Direction::West => ((rows, columns - 1), Position::new(row, last_column)),
+            Direction::East => ((rows, columns + 1), Position::new(row, 0)),
+        }
+    }
+
+    /// Number of plots first reached after each step count up to `steps`, on the repeating map.
+    fn frontiers_repeated(&self, steps: usize) -> Vec<usize> {
+        let start = ((0, 0), self.start);
+        let mut seen = HashSet::from([start]);
+        let mut frontier = vec![start];
+        let mut frontiers = vec![1];
+        for _ in 0..steps {
+            frontier = frontier
+                .into_iter()
+                .flat_map(|at| {
+                    Direction::all().map(|direction| self.neighbour_repeated(at, direction))
+                })
+                .filter(|&(_, position)| self.is_plot(position))
+                .filter(|&next| seen.insert(next))
+                .collect_vec();
+            frontiers.push(frontier.len());
+        }
+        frontiers
+    }
+
+    /// [`Self::reachable`] with the map repeating in every direction, walked step by step.
+    pub fn reachable_repeated(&self, steps: usize) -> usize {
+        self.frontiers_repeated(steps)
+            .into_iter()
+            .skip(steps % 2)
+            .step_by(2)
+            .sum()
+    }
+
+    /// [`Self::reachable_repeated`] for step counts too large to walk.
+    ///
+    /// With a square map and open paths from the start to its edges, the elf reaches one more
+    /// ring of map copies every `width` steps, and the reachable plots grow quadratically in the
+    /// number of rings. The count is sampled at four ring counts, checked to be quadratic with
+    /// the difference table from day 9, and extended with Newton's forward difference formula.
+    pub fn reachable_extrapolated(&self, steps: usize) -> Result<u64> {
+        let width = self.grid.width();
+        ensure!(
+            width > 0 && width == self.grid.height(),
+            "map is {width}x{}, not square",
+            self.grid.height()
+        );
+        let (rings, offset) = (steps / width, steps % width);
+        if rings < 4 {
+            return Ok(self.reachable_repeated(steps) as u64);
+        }
+        let frontiers = self.frontiers_repeated(offset + 3 * width);
+        let reachable = |steps: usize| {
+            frontiers[..=steps]
+                .iter()
+                .skip(steps % 2)
+                .step_by(2)
+                .sum::<usize>()
+        };
+        let samples = (0..4)
+            .map(|ring| reachable(offset + ring * width) as i64)
+            .collect_vec();
+        let table = vec![samples].tap_mut(|table| {
+            while let Some(next) = table.last().and_then(|row| differences(row)) {
+                table.push(next);
+            }
+        });
+        if let Some(cubic) = table.get(3).filter(|row| row.iter().any(|&v| v != 0)) {
+            bail!("reachable plots do not grow quadratically, third difference is {cubic:?}");
+        }
+        // f(n) = sum over k of (n choose k) times the k-th difference at 0
+        let (total, _) =
+            table
+                .iter()
+                .enumerate()
+                .fold((0_i128, 1_i128), |(total, choose), (k, row)| {
+                    (
+                        total + choose * i128::from(row[0]),
+                        choose * (rings as i128 - k as i128) / (k as i128 + 1),
+                    )
+                });
+        total
+            .try_into()
+            .map_err(|_| eyre::eyre!("{total} reachable plots do not fit"))
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Garden;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse_with(input, |c| match c {
+            '.' => Ok(Tile::Plot),
+            '#' => Ok(Tile::Rock),
+            'S' => Ok(Tile::Start),
+            other => bail!("expected '.', '#' or 'S', found {other:?}"),
+        })?;
+        let start = grid
+            .iter()
+            .filter(|&(_, &tile)| tile == Tile::Start)
+            .map(|(position, _)| position)
+            .collect_vec()
+            .pipe(|starts| match starts[..] {
+                [start] => Ok(start),
+                [] => Err(ParseError::at_end(input, "no starting plot 'S'")),
+                _ => Err(ParseError::at(
+                    input,
+                    input
+                        .match_indices('S')
+                        .nth(1)
+                        .map_or("", |(at, _)| &input[at..]),
+                    "more than one starting plot 'S'",
+                )),
+            })?;
+        Ok(Garden { grid, start })
+    }
+
+    fn part_1(garden: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(garden.reachable(64))
+    }
+
+    fn part_2(garden: &Self::Input<'_>) -> Result<Self::Part2> {
+        garden.reachable_extrapolated(26501365)
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::{HashMap, VecDeque};
+
+use eyre::{ContextCompat, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+/// Cubes from `start` to `end` inclusive, as `[x, y, z]` with `start` the lower corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Brick {
+    pub start: [usize; 3],
+    pub end: [usize; 3],
+}
+
+impl Brick {
+    /// The `(x, y)` columns the brick takes up, seen from above.
+    fn footprint(self) -> impl Iterator<Item = (usize, usize)> {
+        (self.start[0]..=self.end[0]).cartesian_product(self.start[1]..=self.end[1])
+    }
+
+    fn height(self) -> usize {
+        self.end[2] - self.start[2] + 1
+    }
+}
+
+/// Bricks once they have all fallen as far as they go, indexed in snapshot order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack {
+    pub bricks: Vec<Brick>,
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Lets the bricks fall from lowest to highest, resting each one on the highest cube under
+    /// its footprint, tracked in a height map of the topmost brick per column.
+    pub fn settle(bricks: &[Brick]) -> Self {
+        let mut settled = bricks.to_vec();
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by = vec![vec![]; bricks.len()];
+        let mut heights = HashMap::<(usize, usize), (usize, usize)>::new();
+        for index in (0..bricks.len()).sorted_by_key(|&index| bricks[index].start[2]) {
+            let brick = bricks[index];
+            let below = brick
+                .footprint()
+                .filter_map(|column| heights.get(&column).copied())
+                .collect_vec();
+            let rest = below.iter().map(|&(top, _)| top).max().unwrap_or_default();
+            below
+                .iter()
+                .filter(|&&(top, _)| top == rest)
+                .map(|&(_, under)| under)
+                .unique()
+                .for_each(|under| {
+                    supports[under].push(index);
+                    supported_by[index].push(under);
+                });
+            let top = rest + brick.height();
+            settled[index] = brick.tap_mut(|brick| {
+                brick.start[2] = rest + 1;
+                brick.end[2] = top;
+            });
+            for column in brick.footprint() {
+                heights.insert(column, (top, index));
+            }
+        }
+        Self {
+            bricks: settled,
+            supports,
+            supported_by,
+        }
+    }
+
+    /// Bricks resting directly on `brick`.
+    pub fn supports(&self, brick: usize) -> &[usize] {
+        &self.supports[brick]
+    }
+
+    /// Bricks `brick` rests directly on, none for bricks on the ground.
+    pub fn supported_by(&self, brick: usize) -> &[usize] {
+        &self.supported_by[brick]
+    }
+
+    /// Whether removing `brick` leaves every brick on it still supported by another one.
+    pub fn is_safe(&self, brick: usize) -> bool {
+        self.supports(brick)
+            .iter()
+            .all(|&above| self.supported_by(above).len() > 1)
+    }
+
+    /// Other bricks that fall when `brick` is removed, counting everything they knock loose.
+    pub fn falling_if_removed(&self, brick: usize) -> usize {
+        let mut fallen = vec![false; self.bricks.len()];
+        fallen[brick] = true;
+        let mut queue = VecDeque::from([brick]);
+        let mut falling = 0;
+        while let Some(brick) = queue.pop_front() {
+            for &above in self.supports(brick) {
+                if !fallen[above] && self.supported_by(above).iter().all(|&under| fallen[under]) {
+                    fallen[above] = true;
+                    falling += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+        falling
+    }
+}
+
+fn corner(corner: &str) -> Result<[usize; 3]> {
+    corner
+        .split(',')
+        .map(|coordinate| coordinate.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?
+        .try_conv::<[usize; 3]>()
+        .ok()
+        .with_context(|| format!("expected x,y,z, found {corner:?}"))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Vec<Brick>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (start, end) = line
+                    .split_once('~')
+                    .context("expected two corners separated by '~'")
+                    .located(input, line)?;
+                let (start, end) = (
+                    corner(start).located(input, start)?,
+                    corner(end).located(input, end)?,
+                );
+                let brick = Brick {
+                    start: std::array::from_fn(|axis| start[axis].min(end[axis])),
+                    end: std::array::from_fn(|axis| start[axis].max(end[axis])),
+                };
+                match brick.start[2] {
+                    0 => Err(ParseError::at(
+                        input,
+                        line,
+                        "bricks cannot be in the ground at z 0",
+                    )),
+                    _ => Ok(brick),
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(bricks: &Self::Input<'_>) -> Result<Self::Part1> {
+        Stack::settle(bricks)
+            .pipe_ref(|stack| {
+                (0..bricks.len())
+                    .filter(|&brick| stack.is_safe(brick))
+                    .count()
+            })
+            .pipe(Ok)
+    }
+
+    fn part_2(bricks: &Self::Input<'_>) -> Result<Self::Part2> {
+        Stack::settle(bricks)
+            .pipe_ref(|stack| {
+                (0..bricks.len())
+                    .map(|brick| stack.falling_if_removed(brick))
+                    .sum::<usize>()
+            })
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use eyre::{bail, ensure, ContextCompat, Result};
+use itertools::Itertools;
+
+use crate::{
+    grid::{Direction, Grid, Position},
+    parse::{Located, ParseError},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    /// Icy slope, only to be walked down in its direction.
+    Slope(Direction),
+}
+
+/// Junctions of the trail map connected by the length of the corridors between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    pub junctions: Vec<Position>,
+    /// Per junction, the junctions a corridor leads to and its length in steps.
+    pub edges: Vec<Vec<(usize, usize)>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Graph {
+    /// Steps on the longest hike from start to end that never visits a junction twice.
+    ///
+    /// Depth first over every simple path, keeping the visited junctions in a bit mask.
+    pub fn longest_hike(&self) -> Option<usize> {
+        self.longest_from(self.start, 1 << self.start)
+    }
+
+    fn longest_from(&self, junction: usize, visited: u64) -> Option<usize> {
+        if junction == self.end {
+            return Some(0);
+        }
+        self.edges[junction]
+            .iter()
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter_map(|&(next, length)| {
+                self.longest_from(next, visited | 1 << next)
+                    .map(|rest| rest + length)
+            })
+            .max()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trails {
+    pub grid: Grid<Tile>,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Trails {
+    /// Whether the tile at `position` can be entered moving in `direction`.
+    fn enterable(&self, position: Position, direction: Direction, slippery: bool) -> bool {
+        match self.grid.get(position) {
+            Some(Tile::Path) => true,
+            Some(&Tile::Slope(slope)) => !slippery || slope == direction,
+            Some(Tile::Forest) | None => false,
+        }
+    }
+
+    fn open_neighbours(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Position, Direction)> + '_ {
+        self.grid.neighbours(position).filter(|&(next, _)| {
+            self.grid
+                .get(next)
+                .is_some_and(|&tile| tile != Tile::Forest)
+        })
+    }
+
+    /// Compresses the map into the corridors between its junctions: the start, the end and every
+    /// tile where paths meet. On `slippery` slopes, corridors can only be walked downhill.
+    pub fn graph(&self, slippery: bool) -> Result<Graph> {
+        let junctions = self
+            .grid
+            .positions()
+            .filter(|&position| {
+                position == self.start
+                    || position == self.end
+                    || self.grid.get(position) != Some(&Tile::Forest)
+                        && self.open_neighbours(position).count() > 2
+            })
+            .collect_vec();
+        ensure!(
+            junctions.len() <= 64,
+            "{} junctions do not fit in the visited mask",
+            junctions.len()
+        );
+        let index = junctions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| (position, index))
+            .collect::<HashMap<_, _>>();
+        let edges = junctions
+            .iter()
+            .map(|&junction| {
+                self.open_neighbours(junction)
+                    .filter_map(|(next, direction)| {
+                        self.follow_corridor(junction, next, direction, slippery)
+                    })
+                    .map(|(end, length)| (index[&end], length))
+                    .collect_vec()
+            })
+            .collect_vec();
+        Ok(Graph {
+            junctions,
+            edges,
+            start: index[&self.start],
+            end: index[&self.end],
+        })
+    }
+
+    /// Walks from `from` into `position` and on until the next junction, returning it with the
+    /// steps taken. `None` for dead ends and slopes walked uphill.
+    fn follow_corridor(
+        &self,
+        mut from: Position,
+        mut position: Position,
+        mut direction: Direction,
+        slippery: bool,
+    ) -> Option<(Position, usize)> {
+        let mut length = 1;
+        loop {
+            if !self.enterable(position, direction, slippery) {
+                return None;
+            }
+            let onwards = self
+                .open_neighbours(position)
+                .filter(|&(next, _)| next != from)
+                .collect_vec();
+            if position == self.start || position == self.end || onwards.len() > 1 {
+                return Some((position, length));
+            }
+            let &[(next, heading)] = onwards.as_slice() else {
+                return None;
+            };
+            (from, position, direction) = (position, next, heading);
+            length += 1;
+        }
+    }
+}
+
+/// The only path tile in `row`, where the hike starts or ends.
+fn opening(grid: &Grid<Tile>, row: usize) -> Result<Position> {
+    grid.row(row)
+        .context("empty map")?
+        .iter()
+        .positions(|&tile| tile == Tile::Path)
+        .exactly_one()
+        .map(|column| Position::new(row, column))
+        .map_err(|columns| eyre::eyre!("row has {} paths, expected 1", columns.count()))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Trails;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse_with(input, |c| match c {
+            '.' => Ok(Tile::Path),
+            '#' => Ok(Tile::Forest),
+            '^' => Ok(Tile::Slope(Direction::North)),
+            'v' => Ok(Tile::Slope(Direction::South)),
+            '<' => Ok(Tile::Slope(Direction::West)),
+            '>' => Ok(Tile::Slope(Direction::East)),
+            other => bail!("expected a path, forest or slope, found {other:?}"),
+        })?;
+        let lines = input.lines().filter(|line| !line.is_empty()).collect_vec();
+        let opening = |row: usize| {
+            opening(&grid, row).located(
+                input,
+                lines.get(row).copied().unwrap_or(&input[input.len()..]),
+            )
+        };
+        let start = opening(0)?;
+        let end = opening(grid.height().saturating_sub(1))?;
+        Ok(Trails { grid, start, end })
+    }
+
+    fn part_1(trails: &Self::Input<'_>) -> Result<Self::Part1> {
+        trails
+            .graph(true)?
+            .longest_hike()
+            .context("no way down the slopes to the end")
+    }
+
+    fn part_2(trails: &Self::Input<'_>) -> Result<Self::Part2> {
+        trails
+            .graph(false)?
+            .longest_hike()
+            .context("no way to the end")
+    }
+}
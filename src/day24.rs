@@ -0,0 +1,171 @@
+use std::ops::RangeInclusive;
+
+use eyre::{ContextCompat, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    math::Rational,
+    parse::{Located, ParseError},
+    Solution,
+};
+
+pub type Vector = [i128; 3];
+
+fn minus(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: Vector, b: Vector) -> i128 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hailstone {
+    pub position: Vector,
+    pub velocity: Vector,
+}
+
+impl Hailstone {
+    pub fn at(self, time: i128) -> Vector {
+        std::array::from_fn(|axis| self.position[axis] + time * self.velocity[axis])
+    }
+
+    /// Where the paths of `self` and `other` cross in the x-y plane, ignoring z and time, but
+    /// not in the past of either hailstone. `None` for parallel paths.
+    pub fn crossing_xy(self, other: Self) -> Option<[Rational; 2]> {
+        let [dx, dy, _] = minus(other.position, self.position);
+        let [vx, vy, _] = self.velocity;
+        let [ux, uy, _] = other.velocity;
+        let determinant = vx * uy - vy * ux;
+        let own_time = Rational::new(dx * uy - dy * ux, determinant)?;
+        let other_time = Rational::new(dx * vy - dy * vx, determinant)?;
+        let zero = Rational::from(0);
+        (own_time >= zero && other_time >= zero).then(|| {
+            [0, 1].map(|axis| {
+                Rational::from(self.position[axis]) + own_time * self.velocity[axis].into()
+            })
+        })
+    }
+
+    /// Whether the thrown `rock` hits this hailstone, at some time that is not in the past.
+    pub fn hit_by(self, rock: Self) -> bool {
+        let offset = minus(self.position, rock.position);
+        let closing = minus(rock.velocity, self.velocity);
+        cross(offset, closing) == [0; 3]
+            && (0..3).all(|axis| match closing[axis] {
+                0 => offset[axis] == 0,
+                closing => offset[axis] % closing == 0 && offset[axis] / closing >= 0,
+            })
+    }
+}
+
+/// Pairs of hailstones whose paths cross inside `area` on both the x and y axis.
+pub fn crossings_inside(hail: &[Hailstone], area: RangeInclusive<i128>) -> usize {
+    let area = Rational::from(*area.start())..=Rational::from(*area.end());
+    hail.iter()
+        .array_combinations()
+        .filter_map(|[one, another]| one.crossing_xy(*another))
+        .filter(|crossing| crossing.iter().all(|value| area.contains(value)))
+        .count()
+}
+
+/// The throw that hits every hailstone.
+///
+/// Seen from the first hailstone, the rock has to pass through the origin and through the path
+/// of each other hailstone, so it lies in the plane through the origin and that path. Each
+/// hailstone crosses the plane of another one exactly at the time the rock hits it. Two hits
+/// at known times pin down the throw. Everything stays in exact integers, the largest being a
+/// position times the normal of a plane, which fits an `i128`.
+pub fn rock(hail: &[Hailstone]) -> Result<Hailstone> {
+    let (&origin, rest) = hail.split_first().context("no hailstones")?;
+    let relative = rest
+        .iter()
+        .map(|stone| Hailstone {
+            position: minus(stone.position, origin.position),
+            velocity: minus(stone.velocity, origin.velocity),
+        })
+        .collect_vec();
+    let hit_time = |stone: Hailstone, plane: Hailstone| {
+        let normal = cross(plane.position, plane.velocity);
+        Rational::new(-dot(stone.position, normal), dot(stone.velocity, normal))?.to_integer()
+    };
+    let throw = (0..relative.len())
+        .array_combinations()
+        .find_map(|[one, another]| {
+            let one_time = hit_time(relative[one], relative[another])?;
+            let another_time = hit_time(relative[another], relative[one])?;
+            let (one_hit, another_hit) = (rest[one].at(one_time), rest[another].at(another_time));
+            let elapsed = another_time - one_time;
+            (elapsed != 0).then_some(())?;
+            let velocity = minus(another_hit, one_hit)
+                .map(|distance| Rational::new(distance, elapsed)?.to_integer())
+                .pipe(|velocity| velocity.iter().copied().collect::<Option<Vec<_>>>())?
+                .try_conv::<Vector>()
+                .ok()?;
+            let position = std::array::from_fn(|axis| one_hit[axis] - one_time * velocity[axis]);
+            Some(Hailstone { position, velocity })
+        })
+        .context("hailstones do not pin down a throw")?;
+    hail.iter()
+        .all(|stone| stone.hit_by(throw))
+        .then_some(throw)
+        .with_context(|| format!("{throw:?} misses some of the hail"))
+}
+
+fn vector(vector: &str) -> Result<Vector> {
+    vector
+        .split(',')
+        .map(|coordinate| coordinate.trim().parse::<i128>())
+        .collect::<Result<Vec<_>, _>>()?
+        .try_conv::<Vector>()
+        .ok()
+        .with_context(|| format!("expected x, y, z, found {vector:?}"))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (position, velocity) = line
+                    .split_once('@')
+                    .context("expected position @ velocity")
+                    .located(input, line)?;
+                Ok(Hailstone {
+                    position: self::vector(position).located(input, position)?,
+                    velocity: self::vector(velocity).located(input, velocity)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(hail: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(crossings_inside(
+            hail,
+            200_000_000_000_000..=400_000_000_000_000,
+        ))
+    }
+
+    fn part_2(hail: &Self::Input<'_>) -> Result<Self::Part2> {
+        rock(hail).map(|rock| rock.position.iter().sum())
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use eyre::{ContextCompat, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+/// Wires splitting the components in two, and how many components end up on each side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut<'input> {
+    pub wires: Vec<(&'input str, &'input str)>,
+    pub sizes: [usize; 2],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wiring<'input> {
+    pub components: Vec<&'input str>,
+    /// Per component, the indices of the components it is wired to.
+    pub connections: Vec<Vec<usize>>,
+}
+
+impl<'input> Wiring<'input> {
+    /// A cut of exactly `wires` wires, if that is the fewest it takes to split the components.
+    ///
+    /// Every wire carries one unit of flow, so by max-flow min-cut the number of wire-disjoint
+    /// paths between two components is the size of the smallest cut separating them. The first
+    /// component ends up on one side of the cut, so trying each other one as the sink finds a
+    /// component on the other side. Augmenting paths are found by BFS and stop one path past
+    /// `wires`, and once the sink is out of reach the components still reachable form one side.
+    pub fn cut(&self, wires: usize) -> Option<Cut<'input>> {
+        (1..self.components.len()).find_map(|sink| {
+            let mut flow = HashMap::<(usize, usize), i32>::new();
+            for _ in 0..wires {
+                let path = self.augmenting_path(&flow, 0, sink).ok()?;
+                for (from, to) in path.into_iter().tuple_windows() {
+                    *flow.entry((from, to)).or_default() += 1;
+                    *flow.entry((to, from)).or_default() -= 1;
+                }
+            }
+            let side = self.augmenting_path(&flow, 0, sink).err()?;
+            let wires = side
+                .iter()
+                .flat_map(|&from| {
+                    self.connections[from]
+                        .iter()
+                        .filter(|to| !side.contains(to))
+                        .map(move |&to| (self.components[from], self.components[to]))
+                })
+                .collect_vec();
+            Some(Cut {
+                wires,
+                sizes: [side.len(), self.components.len() - side.len()],
+            })
+        })
+    }
+
+    /// Shortest path from `source` to `sink` along wires with flow to spare, or every component
+    /// reachable that way when there is none.
+    fn augmenting_path(
+        &self,
+        flow: &HashMap<(usize, usize), i32>,
+        source: usize,
+        sink: usize,
+    ) -> Result<Vec<usize>, HashSet<usize>> {
+        let mut previous = HashMap::from([(source, source)]);
+        let mut queue = VecDeque::from([source]);
+        while let Some(component) = queue.pop_front() {
+            if component == sink {
+                return std::iter::successors(Some(sink), |&at| {
+                    (at != source).then(|| previous[&at])
+                })
+                .collect_vec()
+                .into_iter()
+                .rev()
+                .collect_vec()
+                .pipe(Ok);
+            }
+            for &next in &self.connections[component] {
+                let spare = flow.get(&(component, next)).copied().unwrap_or_default() < 1;
+                if spare && !previous.contains_key(&next) {
+                    previous.insert(next, component);
+                    queue.push_back(next);
+                }
+            }
+        }
+        Err(previous.into_keys().collect())
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const INPUT: Option<&'static str> = None;
+
+    type Input<'input> = Wiring<'input>;
+    type Part1 = usize;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.split_once(": ")
+                    .map(|(name, wired)| (name, wired.split_whitespace().collect_vec()))
+                    .context("expected component: connected components")
+                    .located(input, line)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let components = lines
+            .iter()
+            .flat_map(|(name, wired)| std::iter::once(name).chain(wired))
+            .copied()
+            .unique()
+            .collect_vec();
+        let index = components
+            .iter()
+            .enumerate()
+            .map(|(index, &name)| (name, index))
+            .collect::<HashMap<_, _>>();
+        let mut connections = vec![vec![]; components.len()];
+        for (name, wired) in &lines {
+            for other in wired {
+                connections[index[name]].push(index[other]);
+                connections[index[other]].push(index[name]);
+            }
+        }
+        Ok(Wiring {
+            components,
+            connections,
+        })
+    }
+
+    fn part_1(wiring: &Self::Input<'_>) -> Result<Self::Part1> {
+        wiring
+            .cut(3)
+            .map(
+                |Cut {
+                     sizes: [one, other],
+                     ..
+                 }| one * other,
+            )
+            .context("no three wires split the components in two")
+    }
+
+    fn part_2(_: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok("there is no second puzzle on day 25")
+    }
+}
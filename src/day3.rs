@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+
+use eyre::{Result, WrapErr};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{parse::ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Entry<'input> {
+    val: EntryKind<'input>,
+    coords: Coords,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Coords {
+    x: i32,
+    y: i32,
+}
+
+#[allow(dead_code)]
+fn color(input: &str, substring: &str, color: u16) -> String {
+    input.replace(substring, &format!("\x1b[{color}m{substring}\x1b[0m"))
+}
+
+impl std::fmt::Debug for Coords {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { x, y } = self;
+        write!(f, "[{x}, {y}]")
+    }
+}
+
+impl Coords {
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        let Self { x, y } = self;
+        [
+            Coords { x: x + 1, y: y + 1 },
+            Coords { x: x + 1, y: y - 1 },
+            Coords { x: x - 1, y: y + 1 },
+            Coords { x: x - 1, y: y - 1 },
+            Coords { x, y: y + 1 },
+            Coords { x, y: y - 1 },
+            Coords { x: x + 1, y },
+            Coords { x: x - 1, y },
+        ]
+        .into_iter()
+    }
+}
+
+#[derive(Debug)]
+enum EntryKind<'input> {
+    Number(&'input str),
+    Symbol(&'input str),
+    Dot,
+}
+
+impl<'a> EntryKind<'a> {
+    pub fn len(&self) -> i32 {
+        match self {
+            EntryKind::Number(v) => v.len() as _,
+            EntryKind::Symbol(v) => v.len() as _,
+            EntryKind::Dot => 1,
+        }
+    }
+}
+
+fn take_while<F>(input: &str, take_while: F) -> Option<(&str, &str)>
+where
+    F: Fn(&char) -> bool,
+{
+    input
+        .find(|c: char| !take_while(&c))
+        .unwrap_or(input.len())
+        .pipe(|offset| input.split_at(offset))
+        .pipe(|(matches, rest)| (!matches.is_empty()).then_some((matches, rest)))
+}
+
+fn next_number(input: &str, offset: i32, row: i32) -> Option<(&str, Entry<'_>)> {
+    take_while(input, |c| c.is_ascii_digit()).map(|(val, new_input)| {
+        (
+            new_input,
+            Entry {
+                val: EntryKind::Number(val),
+                coords: Coords { x: offset, y: row },
+            },
+        )
+    })
+}
+fn next_symbol(input: &str, offset: i32, row: i32) -> Option<(&str, Entry<'_>)> {
+    take_while(input, |c| c != &'.' && !c.is_ascii_digit()).map(|(val, new_input)| {
+        (
+            new_input,
+            Entry {
+                val: EntryKind::Symbol(val),
+                coords: Coords { x: offset, y: row },
+            },
+        )
+    })
+}
+
+fn next_dot(input: &str, offset: i32, row: i32) -> Option<(&str, Entry<'_>)> {
+    input.strip_prefix('.').map(|new_input| {
+        (
+            new_input,
+            Entry {
+                val: EntryKind::Dot,
+                coords: Coords { x: offset, y: row },
+            },
+        )
+    })
+}
+
+fn next_entry(input: &str, offset: i32, row: i32) -> Option<(&str, Entry<'_>)> {
+    [next_number, next_dot, next_symbol]
+        .into_iter()
+        .find_map(|matches| matches(input, offset, row))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day3.txt"));
+
+    type Input<'input> = Vec<Entry<'input>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_row, line)| !line.is_empty())
+            .map(|(row, mut line)| {
+                if let Some((index, other)) =
+                    line.char_indices().find(|(_, c)| !c.is_ascii_graphic())
+                {
+                    return Err(ParseError::at(
+                        input,
+                        &line[index..],
+                        format!("expected '.', a digit or a symbol, found {other:?}"),
+                    ));
+                }
+                let mut entries = vec![];
+                while let Some((rest, entry)) = next_entry(
+                    line,
+                    entries.iter().map(|v: &Entry| v.val.len()).sum(),
+                    row as _,
+                ) {
+                    line = rest;
+                    entries.push(entry);
+                }
+                Ok(entries)
+            })
+            .flatten_ok()
+            .collect()
+    }
+
+    fn part_1(entries: &Self::Input<'_>) -> Result<Self::Part1> {
+        entries
+            .iter()
+            .filter_map(|Entry { val, coords }| match val {
+                EntryKind::Number(number) if number.chars().all(|c| c.is_ascii_digit()) => {
+                    Some((number, *coords))
+                }
+                _ => None,
+            })
+            .map(|(engine_number, coords)| {
+                entries
+                    .iter()
+                    .find_map(|entry| match entry {
+                        Entry {
+                            val: EntryKind::Symbol(_symbol),
+                            coords: check,
+                        } => (0..engine_number.len())
+                            .flat_map(|offset| {
+                                coords
+                                    .tap_mut(|coords| coords.x += offset as i32)
+                                    .pipe(Coords::neighbours)
+                            })
+                            .any(|coords| &coords == check)
+                            .then_some(()),
+                        _ => None,
+                    })
+                    .map(|_| ((engine_number, coords), true))
+                    .unwrap_or(((engine_number, coords), false))
+            })
+            .filter_map(|(engine_number, matches)| matches.then_some(engine_number))
+            .map(|(engine_number, _)| engine_number.parse::<i32>().wrap_err("not a number"))
+            .sum::<Result<i32>>()
+    }
+
+    fn part_2(entries: &Self::Input<'_>) -> Result<Self::Part2> {
+        let lookup = entries
+            .iter()
+            .flat_map(|Entry { val, coords }| {
+                (0..val.len()).map(move |offset| {
+                    (
+                        (*coords).tap_mut(|coords| {
+                            coords.x += offset;
+                        }),
+                        val,
+                    )
+                })
+            })
+            .collect::<BTreeMap<_, _>>();
+        entries
+            .iter()
+            .filter(|e| matches!(e.val, EntryKind::Symbol("*")))
+            .map(|e| {
+                e.coords
+                    .neighbours()
+                    .filter_map(|coord| {
+                        lookup.get(&coord).and_then(|entry_kind| match entry_kind {
+                            EntryKind::Number(number) => Some(number.parse::<i32>()),
+                            _ => None,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .wrap_err("bad number")
+                    .map(|numbers| numbers.into_iter().unique().collect_vec())
+            })
+            .filter_map_ok(|values| {
+                values
+                    .try_conv::<[i32; 2]>()
+                    .ok()
+                    .map(|[one, two]| one * two)
+            })
+            .sum::<Result<i32>>()
+    }
+}
@@ -0,0 +1,99 @@
+use std::{collections::HashSet, iter::once};
+
+use eyre::{ContextCompat, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+type Scored = (usize, usize);
+
+fn copies(current: Scored, values: &[Scored]) -> Vec<Scored> {
+    once(current)
+        // empty()
+        .chain(current.pipe(|(id, count)| {
+            (0..count)
+                .map(move |count| id + count)
+                .filter_map(|id| values.get(id))
+                .flat_map(|current| copies(*current, values))
+        }))
+        .collect()
+}
+
+pub type Card = (usize, (HashSet<usize>, Vec<usize>));
+
+fn score(cards: &[Card]) -> impl Iterator<Item = Scored> + '_ {
+    cards.iter().map(|(id, (winning, actual))| {
+        actual
+            .iter()
+            .filter(|actual| winning.contains(actual))
+            .count()
+            .pipe(|count| (*id, count))
+    })
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day4.txt"));
+
+    type Input<'input> = Vec<Card>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let numbers = |numbers: &str| {
+            numbers
+                .split_ascii_whitespace()
+                .map(|number| number.parse::<usize>().located(input, number))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (card, scores) = line
+                    .split_once(':')
+                    .context("no ':' after the card")
+                    .located(input, line)?;
+                let id = card
+                    .split_once(' ')
+                    .context("no card id")
+                    .located(input, card)
+                    .and_then(|(_, id)| id.trim().parse::<usize>().located(input, id))?;
+                let (winning, actual) = scores
+                    .split_once('|')
+                    .context("no '|' between winning and actual numbers")
+                    .located(input, scores)?;
+                Ok((
+                    id,
+                    (numbers(winning)?.into_iter().collect(), numbers(actual)?),
+                ))
+            })
+            .collect()
+    }
+
+    fn part_1(cards: &Self::Input<'_>) -> Result<Self::Part1> {
+        score(cards)
+            .map(|(_id, count)| match count {
+                0 => 0,
+                count => 2usize.pow((count as u32) - 1),
+            })
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+
+    fn part_2(cards: &Self::Input<'_>) -> Result<Self::Part2> {
+        score(cards).collect_vec().pipe(|scored| {
+            scored
+                .iter()
+                .flat_map(|current| copies(*current, &scored[..]))
+                .count()
+                .pipe(Ok)
+        })
+    }
+}
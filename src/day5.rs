@@ -0,0 +1,257 @@
+use std::{collections::BTreeMap, iter::once};
+
+use eyre::{ensure, ContextCompat, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    interval::{Interval, IntervalSet},
+    parse::{Located, ParseError},
+    Solution,
+};
+
+/// Moves every value in `source` by `offset`.
+#[derive(Clone, Copy, Debug)]
+pub struct MappingPart {
+    pub source: Interval,
+    pub offset: i64,
+}
+
+impl MappingPart {
+    pub fn get(&self, key: i64) -> Option<i64> {
+        self.source.contains(key).then_some(key + self.offset)
+    }
+}
+
+/// A piecewise-linear function: values inside a part's source move by its offset, all other
+/// values map to themselves.
+#[derive(Clone, Debug, Default)]
+pub struct Mapping(pub Vec<MappingPart>);
+
+impl Mapping {
+    pub fn get(&self, key: i64) -> Option<i64> {
+        self.0.iter().find_map(|part| part.get(key))
+    }
+
+    pub fn apply(&self, key: i64) -> i64 {
+        self.get(key).unwrap_or(key)
+    }
+
+    /// Values moved by one of the parts.
+    pub fn sources(&self) -> IntervalSet {
+        self.0.iter().map(|part| part.source).collect()
+    }
+
+    /// The single mapping that applies `self`, then `next`.
+    pub fn then(&self, next: &Mapping) -> Mapping {
+        let next_sources = next.sources();
+        let through_self = self.0.iter().flat_map(|part| {
+            let image = IntervalSet::from(part.source.shifted(part.offset));
+            next.0
+                .iter()
+                .map(|next_part| {
+                    (
+                        image.intersection(&next_part.source.into()),
+                        part.offset + next_part.offset,
+                    )
+                })
+                .chain(once((image.difference(&next_sources), part.offset)))
+                .flat_map(|(moved, offset)| {
+                    moved
+                        .shifted(-part.offset)
+                        .intervals()
+                        .map(|source| MappingPart { source, offset })
+                        .collect_vec()
+                })
+                .collect_vec()
+        });
+        let sources = self.sources();
+        let only_through_next = next.0.iter().flat_map(|next_part| {
+            IntervalSet::from(next_part.source)
+                .difference(&sources)
+                .intervals()
+                .map(|source| MappingPart {
+                    source,
+                    offset: next_part.offset,
+                })
+                .collect_vec()
+        });
+        through_self
+            .chain(only_through_next)
+            .filter(|part| part.offset != 0)
+            .sorted_by_key(|part| part.source)
+            .collect_vec()
+            .pipe(Mapping)
+    }
+
+    /// The mapping undoing `self`. It only exists when the parts shuffle the values they cover
+    /// among themselves, which the almanac's maps all do.
+    pub fn inverse(&self) -> Result<Mapping> {
+        let images = self
+            .0
+            .iter()
+            .map(|part| part.source.shifted(part.offset))
+            .collect::<IntervalSet>();
+        let covered = self.0.iter().map(|part| part.source.len()).sum::<i64>();
+        ensure!(
+            images == self.sources() && images.len() == covered,
+            "mapping is not one-to-one, {images:?} are mapped to"
+        );
+        self.0
+            .iter()
+            .map(|part| MappingPart {
+                source: part.source.shifted(part.offset),
+                offset: -part.offset,
+            })
+            .sorted_by_key(|part| part.source)
+            .collect_vec()
+            .pipe(Mapping)
+            .pipe(Ok)
+    }
+
+    /// Every value in `seeds` mapped through the parts, values outside of all parts map to
+    /// themselves.
+    pub fn map_ranges(&self, seeds: &IntervalSet) -> IntervalSet {
+        self.0
+            .iter()
+            .fold(
+                (IntervalSet::new(), seeds.clone()),
+                |(mapped, unmapped), part| {
+                    unmapped.intersection(&part.source.into()).pipe(|hit| {
+                        (
+                            mapped.union(&hit.shifted(part.offset)),
+                            unmapped.difference(&hit),
+                        )
+                    })
+                },
+            )
+            .pipe(|(mapped, unmapped)| mapped.union(&unmapped))
+    }
+}
+
+pub const MAPPING_ORDER: &[(&str, &str)] = &[
+    ("seed", "soil"),
+    ("soil", "fertilizer"),
+    ("fertilizer", "water"),
+    ("water", "light"),
+    ("light", "temperature"),
+    ("temperature", "humidity"),
+    ("humidity", "location"),
+];
+
+#[derive(Debug, Clone)]
+pub struct Almanac<'input> {
+    pub seeds: Vec<i64>,
+    pub maps: BTreeMap<(&'input str, &'input str), Mapping>,
+}
+
+impl Almanac<'_> {
+    fn mapping(&self, mapping: &(&'static str, &'static str)) -> Result<&Mapping> {
+        self.maps
+            .get(mapping)
+            .with_context(|| format!("bad mapping: {mapping:?}"))
+    }
+
+    /// Every map from seed to location folded into one.
+    pub fn seed_to_location(&self) -> Result<Mapping> {
+        MAPPING_ORDER
+            .iter()
+            .try_fold(Mapping::default(), |composed, mapping| {
+                self.mapping(mapping).map(|mapping| composed.then(mapping))
+            })
+    }
+
+    /// The seeds line read as pairs of range start and length, as part 2 does.
+    pub fn seed_ranges(&self) -> Result<IntervalSet> {
+        ensure!(
+            self.seeds.len().is_multiple_of(2),
+            "{} seeds do not pair up into ranges",
+            self.seeds.len()
+        );
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| Interval::with_length(pair[0], pair[1]))
+            .collect::<IntervalSet>()
+            .pipe(Ok)
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day5.txt"));
+
+    type Input<'input> = Almanac<'input>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let numbers = |line: &str| {
+            line.split_whitespace()
+                .map(|number| number.parse::<i64>().located(input, number))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let (head, maps) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "no empty line after the seeds"))?;
+        let seeds = head
+            .split_once(": ")
+            .context("no ': ' after seeds")
+            .located(input, head)
+            .and_then(|(_, seeds)| numbers(seeds))?;
+        maps.split("\n\n")
+            .filter(|map| !map.trim().is_empty())
+            .map(|map| {
+                let (head, mappings) = map
+                    .split_once(":\n")
+                    .context("no ':' after the map header")
+                    .located(input, map)?;
+                let kind = head
+                    .split_once(' ')
+                    .and_then(|(name, _)| name.split_once("-to-"))
+                    .context("expected a '<source>-to-<destination> map' header")
+                    .located(input, head)?;
+                mappings
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(|line| {
+                        numbers(line)?
+                            .try_conv::<[i64; 3]>()
+                            .map_err(|numbers| {
+                                ParseError::at(
+                                    input,
+                                    line,
+                                    format!("expected 3 numbers, found {}", numbers.len()),
+                                )
+                            })
+                            .map(|[destination, source, length]| MappingPart {
+                                source: Interval::with_length(source, length),
+                                offset: destination - source,
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|mappings| (kind, Mapping(mappings)))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map(|maps| Almanac { seeds, maps })
+    }
+
+    fn part_1(almanac: &Self::Input<'_>) -> Result<Self::Part1> {
+        let seed_to_location = almanac.seed_to_location()?;
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| seed_to_location.apply(seed))
+            .min()
+            .context("no seeds")
+    }
+
+    fn part_2(almanac: &Self::Input<'_>) -> Result<Self::Part2> {
+        almanac
+            .seed_to_location()?
+            .map_ranges(&almanac.seed_ranges()?)
+            .min()
+            .context("no seeds")
+    }
+}
@@ -0,0 +1,92 @@
+use std::ops::Mul;
+
+use eyre::{ContextCompat, Result, WrapErr};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+fn ways_to_win(time: usize, distance: usize) -> usize {
+    (0..time)
+        .filter_map(move |held| {
+            time.checked_sub(held)
+                .map(|remaining_time| remaining_time.mul(held.pipe(|speed| speed)))
+                .map(|distance| (held, distance))
+        })
+        .filter(move |(_held, moved)| moved.gt(&distance))
+        .count()
+}
+
+fn numbers(numbers: &[&str]) -> Result<Vec<usize>> {
+    numbers
+        .iter()
+        .map(|v| {
+            v.parse::<usize>()
+                .wrap_err_with(|| format!("bad number: {v:?}"))
+        })
+        .collect()
+}
+
+fn joined_number(numbers: &[&str]) -> Result<usize> {
+    numbers.join("").pipe(|v| {
+        v.parse::<usize>()
+            .wrap_err_with(|| format!("bad number: {v:?}"))
+    })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day6.txt"));
+
+    /// The numbers on the time and the distance line, as written.
+    type Input<'input> = [Vec<&'input str>; 2];
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.split_once(':')
+                    .context("no ':' after the header")
+                    .located(input, line)
+                    .and_then(|(_, numbers)| {
+                        numbers
+                            .split_whitespace()
+                            .map(|number| {
+                                number
+                                    .parse::<usize>()
+                                    .map(|_| number)
+                                    .located(input, number)
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .try_conv::<[Vec<&str>; 2]>()
+            .map_err(|lines| {
+                ParseError::at_end(input, format!("expected 2 lines, found {}", lines.len()))
+            })
+    }
+
+    fn part_1([time, distance]: &Self::Input<'_>) -> Result<Self::Part1> {
+        (numbers(time)?, numbers(distance)?)
+            .pipe(|(time, distance)| time.into_iter().zip(distance).collect_vec())
+            .into_iter()
+            .map(|(time, distance)| ways_to_win(time as _, distance as _))
+            .product::<usize>()
+            .pipe(Ok)
+    }
+
+    fn part_2([time, distance]: &Self::Input<'_>) -> Result<Self::Part2> {
+        (joined_number(time)?, joined_number(distance)?)
+            .pipe(|(time, distance)| ways_to_win(time, distance))
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,205 @@
+use eyre::{bail, ContextCompat, Result, WrapErr};
+use itertools::Itertools;
+use std::{collections::BTreeMap, iter::once};
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+pub const STRENGTHS: &[char] = &[
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+pub const STRENGTHS_PART_2: &[char] = &[
+    'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J',
+];
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+fn kind(hand: &str) -> Result<HandKind> {
+    use HandKind::*;
+    hand.chars()
+        .sorted()
+        .chunk_by(|&v| v)
+        .into_iter()
+        .map(|(value, occurrences)| (value, occurrences.into_iter().count()))
+        .collect_vec()
+        .pipe(|occurences| {
+            match occurences
+                .into_iter()
+                .map(|(_char, count)| count)
+                .sorted()
+                .collect_vec()
+                .as_slice()
+            {
+                [5] => Ok(FiveOfAKind),
+                [1, 4] => Ok(FourOfAKind),
+                [2, 3] => Ok(FullHouse),
+                [1, 1, 3] => Ok(ThreeOfAKind),
+                [1, 2, 2] => Ok(TwoPair),
+                [1, 1, 1, 2] => Ok(OnePair),
+                [1, 1, 1, 1, 1] => Ok(HighCard),
+                other => bail!("'{hand}' produced an unhandled variant: {other:?}"),
+            }
+        })
+}
+
+fn card_strength(card: char) -> usize {
+    card.pipe(|value| {
+        STRENGTHS
+            .iter()
+            .find_position(|&&e| e == value)
+            .map(|(value, _)| STRENGTHS.len() - value)
+            .expect("cards are checked while parsing")
+    })
+}
+
+fn card_strength_part_2(card: char) -> usize {
+    card.pipe(|value| {
+        STRENGTHS_PART_2
+            .iter()
+            .find_position(|&&e| e == value)
+            .map(|(value, _)| STRENGTHS_PART_2.len() - value)
+            .expect("cards are checked while parsing")
+    })
+}
+
+fn boxed<'a, T: Iterator<Item = I> + 'a, I: 'static>(
+    iterator: T,
+) -> Box<dyn Iterator<Item = I> + 'a> {
+    Box::new(iterator)
+}
+
+fn combinations<'input>(
+    possible_cards: &'input [&'input [char]],
+) -> impl Iterator<Item = Vec<char>> + 'input {
+    match possible_cards {
+        [] => once(vec![]).pipe(boxed),
+        [head, tail @ ..] => head
+            .iter()
+            .copied()
+            .flat_map(|head| {
+                combinations(tail).map(move |tail| tail.tap_mut(|tail| tail.insert(0, head)))
+            })
+            .pipe(boxed),
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day7.txt"));
+
+    type Input<'input> = Vec<(&'input str, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (hand, bet) = line
+                    .split_once(' ')
+                    .context("no space between hand and bet")
+                    .located(input, line)?;
+                if let Some((index, card)) = hand
+                    .char_indices()
+                    .find(|(_, card)| !STRENGTHS.contains(card))
+                {
+                    return Err(ParseError::at(
+                        input,
+                        &hand[index..],
+                        format!("unknown card: {card:?}"),
+                    ));
+                }
+                bet.parse::<usize>()
+                    .wrap_err("bad bet")
+                    .located(input, bet)
+                    .map(|bet| (hand, bet))
+            })
+            .collect()
+    }
+
+    fn part_1(lines: &Self::Input<'_>) -> Result<Self::Part1> {
+        lines
+            .iter()
+            .map(|(hand, bet)| {
+                kind(hand).map(|kind| {
+                    (
+                        (hand, bet),
+                        (kind, hand.chars().map(card_strength).collect_vec()),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted_unstable_by_key(|(_, (kind, strengths))| (kind.to_owned(), strengths.clone()))
+            .enumerate()
+            .map(|(i, v)| (i + 1, v))
+            .map(|(rank, ((_hand, bet), (_kind, _strongest)))| rank * bet)
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+
+    fn part_2(lines: &Self::Input<'_>) -> Result<Self::Part2> {
+        let variant_mapping = STRENGTHS_PART_2
+            .iter()
+            .map(|&c| match c {
+                'J' => (c, STRENGTHS_PART_2.to_vec()),
+                other => (c, [other].to_vec()),
+            })
+            .collect::<BTreeMap<_, _>>();
+        lines
+            .iter()
+            .map(|(hand, bet)| {
+                hand.chars()
+                    .map(|c| {
+                        variant_mapping
+                            .get(&c)
+                            .map(|v| v.as_slice())
+                            .with_context(|| format!("invalid card: {c:?}"))
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .and_then(|v| {
+                        combinations(&v)
+                            .map(move |new_hand| {
+                                kind(&new_hand.into_iter().join("")).map(|kind| {
+                                    (
+                                        (hand, bet),
+                                        (
+                                            kind,
+                                            hand.chars().map(card_strength_part_2).collect_vec(),
+                                        ),
+                                    )
+                                })
+                            })
+                            .process_results(|variants| {
+                                variants
+                                    .max_by_key(|(_, (kind, strengths))| (*kind, strengths.clone()))
+                            })?
+                            .context("no variants")
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted_unstable_by_key(|(_, (kind, strengths))| (kind.to_owned(), strengths.clone()))
+            .enumerate()
+            .map(|(i, v)| (i + 1, v))
+            .map(|(rank, ((_hand, &bet), (_kind, _strongest)))| rank * bet)
+            .sum::<usize>()
+            .pipe(Ok)
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, HashMap};
+
+use eyre::{bail, ensure, ContextCompat, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    math::{crt, lcm},
+    parse::{Located, ParseError},
+    Solution,
+};
+
+pub type Lookup<'input> = BTreeMap<&'input str, BTreeMap<char, &'input str>>;
+
+#[derive(Debug, Clone)]
+pub struct Network<'input> {
+    pub directions: &'input str,
+    pub lookup: Lookup<'input>,
+    pub first: &'input str,
+}
+
+/// When a ghost walking the network stands on a node ending in `Z`, counted in steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghost {
+    /// Steps on a `Z` node before the walk starts repeating, never to come back.
+    pub transient: Vec<u64>,
+    /// First step of the repeating part of the walk.
+    pub cycle_start: u64,
+    pub period: u64,
+    /// Steps on a `Z` node during the first pass through the repeating part, which come back
+    /// every `period` steps.
+    pub cycle: Vec<u64>,
+}
+
+impl Ghost {
+    pub fn on_z(&self, step: u64) -> bool {
+        match step.checked_sub(self.cycle_start) {
+            None => self.transient.contains(&step),
+            Some(_) => self
+                .cycle
+                .iter()
+                .any(|&hit| step >= hit && (step - hit).is_multiple_of(self.period)),
+        }
+    }
+}
+
+impl<'input> Network<'input> {
+    /// Walks from `start` until the same node comes up at the same point in the directions,
+    /// after which the walk repeats.
+    pub fn ghost(&self, start: &'input str) -> Result<Ghost> {
+        let directions = self.directions.chars().collect_vec();
+        ensure!(!directions.is_empty(), "no directions");
+        let mut seen = HashMap::new();
+        let mut hits = vec![];
+        let mut node = start;
+        for step in 0_u64.. {
+            let instruction = step as usize % directions.len();
+            if let Some(&cycle_start) = seen.get(&(node, instruction)) {
+                let (transient, cycle) = hits.into_iter().partition(|&hit| hit < cycle_start);
+                return Ok(Ghost {
+                    transient,
+                    cycle_start,
+                    period: step - cycle_start,
+                    cycle,
+                });
+            }
+            seen.insert((node, instruction), step);
+            if node.ends_with('Z') {
+                hits.push(step);
+            }
+            node = self
+                .lookup
+                .get(node)
+                .with_context(|| format!("in unknown location: {node:?}"))?
+                .get(&directions[instruction])
+                .context("invalid direction")?;
+        }
+        unreachable!("a finite network repeats eventually")
+    }
+
+    /// Steps until every ghost starting on a node ending in `A` stands on a node ending in `Z`.
+    ///
+    /// A common step before all ghosts are going in circles has to be a transient hit of the
+    /// ghost that starts repeating last, so those are tried first. After that, ghosts that
+    /// reach `Z` exactly at multiples of their period meet at the least common multiple of the
+    /// periods. Otherwise every choice of one repeating hit per ghost is a system of
+    /// congruences, solved with the Chinese remainder theorem.
+    pub fn ghost_steps(&self) -> Result<u64> {
+        let ghosts = self
+            .lookup
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|&start| self.ghost(start))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(!ghosts.is_empty(), "no nodes ending in A");
+        if let Some(step) = ghosts
+            .iter()
+            .flat_map(|ghost| ghost.transient.iter().copied())
+            .sorted()
+            .find(|&step| ghosts.iter().all(|ghost| ghost.on_z(step)))
+        {
+            return Ok(step);
+        }
+        let all_cycling = ghosts
+            .iter()
+            .map(|ghost| ghost.cycle_start)
+            .max()
+            .unwrap_or_default();
+        // the first solution no earlier than `all_cycling`
+        let from = |residue: u64, modulus: u64| {
+            residue + all_cycling.saturating_sub(residue).div_ceil(modulus) * modulus
+        };
+        if ghosts
+            .iter()
+            .all(|ghost| matches!(ghost.cycle[..], [hit] if hit.is_multiple_of(ghost.period)))
+        {
+            let period = ghosts.iter().map(|ghost| ghost.period).fold(1, lcm);
+            return Ok(from(period, period));
+        }
+        ghosts
+            .iter()
+            .map(|ghost| ghost.cycle.iter().map(move |&hit| (hit, ghost.period)))
+            .multi_cartesian_product()
+            .filter_map(|hits| {
+                crt(hits
+                    .into_iter()
+                    .map(|(hit, period)| (i128::from(hit), i128::from(period))))
+            })
+            .map(|(residue, modulus)| from(residue as u64, modulus as u64))
+            .min()
+            .context("ghosts never all stand on nodes ending in Z at once")
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day8.txt"));
+
+    type Input<'input> = Network<'input>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (directions, maps) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "no empty line after the directions"))?;
+        if directions.is_empty() {
+            return Err(ParseError::at(input, directions, "no directions"));
+        }
+        if let Some((index, other)) = directions
+            .char_indices()
+            .find(|(_, direction)| !matches!(direction, 'L' | 'R'))
+        {
+            return Err(ParseError::at(
+                input,
+                &directions[index..],
+                format!("unknown direction: {other:?}"),
+            ));
+        }
+        let lines = maps
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|line| {
+                line.split_once(" = ")
+                    .context("no ' = '")
+                    .and_then(|(key, leftright)| {
+                        leftright
+                            .trim_start_matches('(')
+                            .trim_end_matches(')')
+                            .split_once(", ")
+                            .context("no ', ' between left and right")
+                            .map(|(left, right)| (key, (left, right)))
+                    })
+                    .located(input, line)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let first = lines
+            .first()
+            .ok_or_else(|| ParseError::at_end(input, "no nodes"))?
+            .0;
+        lines
+            .into_iter()
+            .map(|(key, (left, right))| {
+                (
+                    key,
+                    BTreeMap::new()
+                        .tap_mut(|map| {
+                            map.insert('L', left);
+                        })
+                        .tap_mut(|map| {
+                            map.insert('R', right);
+                        }),
+                )
+            })
+            .collect::<BTreeMap<_, _>>()
+            .pipe(|lookup| Network {
+                directions: directions.trim(),
+                lookup,
+                first,
+            })
+            .pipe(Ok)
+    }
+
+    fn part_1(
+        Network {
+            directions,
+            lookup,
+            first,
+        }: &Self::Input<'_>,
+    ) -> Result<Self::Part1> {
+        let mut current = if lookup.contains_key("AAA") {
+            "AAA"
+        } else {
+            first
+        };
+        for (step, direction) in directions.chars().cycle().enumerate() {
+            current = lookup
+                .get(current)
+                .with_context(|| format!("in unknown location: {current:?}"))?
+                .get(&direction)
+                .context("invalid direction")?;
+            if current == "ZZZ" {
+                return Ok(step + 1);
+            }
+        }
+        // only an empty `cycle` ever ends
+        bail!("no directions")
+    }
+
+    fn part_2(network: &Self::Input<'_>) -> Result<Self::Part2> {
+        network.ghost_steps()
+    }
+}
@@ -0,0 +1,129 @@
+use std::ops::Sub;
+
+use eyre::Result;
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::{
+    parse::{Located, ParseError},
+    Solution,
+};
+
+/// Differences between consecutive values, `None` once they are all zero.
+pub fn differences<T>(line: &[T]) -> Option<Vec<T>>
+where
+    T: Copy + Default + PartialEq + Sub<Output = T>,
+{
+    line.iter().any(|&v| v != T::default()).then(|| {
+        line.iter()
+            .zip(line.iter().skip(1))
+            .map(|(&prev, &next)| next - prev)
+            .collect()
+    })
+}
+
+fn difference_tables(input: &[Vec<i32>]) -> Vec<Vec<Vec<i32>>> {
+    input
+        .iter()
+        .cloned()
+        .map(|line| vec![line])
+        .map(|line| {
+            line.tap_mut(|line| {
+                while let Some(differences) = line.last().and_then(|last| differences(last)) {
+                    line.push(differences);
+                }
+            })
+        })
+        .collect_vec()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: Option<&'static str> = Some(include_str!("../inputs/day9.txt"));
+
+    type Input<'input> = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|v| v.parse::<i32>().located(input, v))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        difference_tables(input)
+            .into_iter()
+            .map(|line| {
+                (0..line.len()).rev().pipe(|range| {
+                    range
+                        .clone()
+                        .zip(range.skip(1))
+                        .fold(line, |line, (prev, next)| {
+                            line.tap_mut(|line| {
+                                line.get(next)
+                                    .zip(line.get(prev))
+                                    .and_then(|(next, prev)| {
+                                        next.last()
+                                            .zip(prev.last())
+                                            .map(|(value, prediciton)| value + prediciton)
+                                    })
+                                    .unwrap_or_default()
+                                    .pipe(|prediction| {
+                                        line.get_mut(next).unwrap().push(prediction);
+                                    })
+                            })
+                        })
+                })
+            })
+            .filter_map(|line| {
+                line.first()
+                    .and_then(|subsequence| subsequence.last())
+                    .copied()
+            })
+            .sum::<i32>()
+            .pipe(Ok)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        difference_tables(input)
+            .into_iter()
+            .map(|line| {
+                (0..line.len()).rev().pipe(|range| {
+                    range
+                        .clone()
+                        .zip(range.skip(1))
+                        .fold(line, |line, (prev, next)| {
+                            line.tap_mut(|line| {
+                                line.get(next)
+                                    .zip(line.get(prev))
+                                    .and_then(|(next, prev)| {
+                                        next.first()
+                                            .zip(prev.first())
+                                            .map(|(value, prediciton)| value - prediciton)
+                                    })
+                                    .unwrap_or_default()
+                                    .pipe(|prediction| {
+                                        line.get_mut(next).unwrap().insert(0, prediction);
+                                    })
+                            })
+                        })
+                })
+            })
+            .filter_map(|line| {
+                line.first()
+                    .and_then(|subsequence| subsequence.first())
+                    .copied()
+            })
+            .sum::<i32>()
+            .pipe(Ok)
+    }
+}
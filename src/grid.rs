@@ -0,0 +1,371 @@
+//! Dense 2D grid shared by the days whose input is a character map.
+
+use std::{fmt::Display, str::FromStr};
+
+use eyre::{bail, eyre, Result};
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::parse::{Located, ParseError};
+
+#[derive(Clone, PartialEq, Eq, Copy, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    West,
+    South,
+}
+
+impl Direction {
+    pub const fn all() -> [Self; 4] {
+        [Self::North, Self::South, Self::East, Self::West]
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Self::South,
+            Direction::East => Self::West,
+            Direction::West => Self::East,
+            Direction::South => Self::North,
+        }
+    }
+
+    pub fn moved(self, in_direction: Turn) -> Self {
+        match in_direction {
+            Turn::Straight => self,
+            Turn::Left => match self {
+                Direction::North => Direction::West,
+                Direction::East => Direction::North,
+                Direction::West => Direction::South,
+                Direction::South => Direction::East,
+            },
+            Turn::Right => match self {
+                Direction::North => Direction::East,
+                Direction::East => Direction::South,
+                Direction::West => Direction::North,
+                Direction::South => Direction::West,
+            },
+        }
+    }
+
+    /// The turn that takes `self` to `other`, `None` when `other` is its opposite.
+    pub fn turn_to(self, other: Self) -> Option<Turn> {
+        [Turn::Straight, Turn::Left, Turn::Right]
+            .into_iter()
+            .find(|&turn| self.moved(turn) == other)
+    }
+}
+
+impl std::fmt::Debug for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::North => "/\\",
+            Direction::East => "->",
+            Direction::West => "<-",
+            Direction::South => "\\/",
+        }
+        .pipe(|v| write!(f, "[{v}]"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Turn {
+    Straight,
+    Left,
+    Right,
+}
+
+#[derive(Clone, PartialEq, Eq, Copy, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Debug for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { column, row } = *self;
+        write!(f, "[{row}, {column}]")
+    }
+}
+
+impl Position {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// The adjacent position, or `None` when it would leave the first row or column.
+    pub fn neighbour(self, direction: Direction) -> Option<Self> {
+        let Self { column, row } = self;
+        match direction {
+            Direction::North => row.checked_sub(1).map(|row| Self { column, row }),
+            Direction::South => row.checked_add(1).map(|row| Self { column, row }),
+            Direction::East => column.checked_add(1).map(|column| Self { column, row }),
+            Direction::West => column.checked_sub(1).map(|column| Self { column, row }),
+        }
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = (Self, Direction)> {
+        Direction::all()
+            .into_iter()
+            .filter_map(move |direction| self.neighbour(direction).map(|n| (n, direction)))
+    }
+
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Self> {
+        [
+            (Some(Direction::North), None),
+            (Some(Direction::South), None),
+            (None, Some(Direction::East)),
+            (Some(Direction::North), Some(Direction::East)),
+            (Some(Direction::South), Some(Direction::East)),
+            (None, Some(Direction::West)),
+            (Some(Direction::North), Some(Direction::West)),
+            (Some(Direction::South), Some(Direction::West)),
+        ]
+        .into_iter()
+        .filter_map(move |(vertical, horizontal)| {
+            [vertical, horizontal]
+                .into_iter()
+                .flatten()
+                .try_fold(self, |position, direction| position.neighbour(direction))
+        })
+    }
+
+    /// Direction of an orthogonally adjacent `other`.
+    pub fn direction(self, other: Self) -> Option<Direction> {
+        self.neighbours()
+            .find_map(|(position, direction)| (position == other).then_some(direction))
+    }
+}
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character, one row per non-empty line.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let mut width = None;
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .try_fold(Vec::new(), |mut cells, line| {
+                line.char_indices().try_for_each(|(index, c)| {
+                    cell(c)
+                        .located(input, &line[index..])
+                        .map(|cell| cells.push(cell))
+                })?;
+                match *width.get_or_insert(line.chars().count()) {
+                    expected if expected == line.chars().count() => Ok(cells),
+                    expected => Err(ParseError::at(
+                        input,
+                        line,
+                        format!(
+                            "row has {} cells, expected {expected}",
+                            line.chars().count()
+                        ),
+                    )),
+                }
+            })
+            .map(|cells| Self {
+                width: width.unwrap_or_default(),
+                cells,
+            })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        match self.width {
+            0 => 0,
+            width => self.cells.len() / width,
+        }
+    }
+
+    pub fn contains(&self, Position { row, column }: Position) -> bool {
+        row < self.height() && column < self.width
+    }
+
+    fn index(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.row * self.width + position.column)
+    }
+
+    fn position(&self, index: usize) -> Position {
+        Position {
+            row: index / self.width,
+            column: index % self.width,
+        }
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Replaces the cell at `position`, returning the previous value.
+    pub fn set(&mut self, position: Position, value: T) -> Result<T> {
+        self.get_mut(position)
+            .map(|cell| std::mem::replace(cell, value))
+            .ok_or_else(|| eyre!("position out of bounds: {position:?}"))
+    }
+
+    pub fn swap(&mut self, first: Position, other: Position) -> Result<()> {
+        match (self.index(first), self.index(other)) {
+            (Some(first), Some(other)) => {
+                self.cells.swap(first, other);
+                Ok(())
+            }
+            _ => bail!("cannot swap {first:?} with {other:?}: position out of bounds"),
+        }
+    }
+
+    /// The adjacent position, if it is still inside the grid.
+    pub fn neighbour(&self, position: Position, direction: Direction) -> Option<Position> {
+        position
+            .neighbour(direction)
+            .filter(|&position| self.contains(position))
+    }
+
+    pub fn neighbours(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Position, Direction)> + '_ {
+        position
+            .neighbours()
+            .filter(|&(position, _)| self.contains(position))
+    }
+
+    pub fn neighbours_with_diagonals(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours_with_diagonals()
+            .filter(|&position| self.contains(position))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.cells.len()).map(|index| self.position(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.position(index), cell))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height()).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height()).filter_map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height())
+            .filter(move |_| column < self.width)
+            .map(move |row| &self.cells[row * self.width + column])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Rows become columns and columns become rows.
+    pub fn transposed(&self) -> Self {
+        Self {
+            width: self.height(),
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn rotated_clockwise(&self) -> Self {
+        Self {
+            width: self.height(),
+            cells: self
+                .columns()
+                .flat_map(|column| column.collect_vec().into_iter().rev())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn rotated_counter_clockwise(&self) -> Self {
+        Self {
+            width: self.height(),
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|column| self.column(column))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    /// Parses every character on its own through `T`'s `FromStr`.
+    pub fn parse_cells(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, |c| {
+            c.encode_utf8(&mut [0; 4])
+                .parse::<T>()
+                .map_err(|e| eyre!("{e}"))
+        })
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, |c| T::try_from(c).map_err(|e| eyre!("{e}")))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.rows().try_for_each(|row| {
+            row.iter().try_for_each(|cell| cell.fmt(f))?;
+            writeln!(f)
+        })
+    }
+}
@@ -0,0 +1,64 @@
+//! Runtime puzzle input loading.
+//!
+//! Every day accepts an optional first argument: a path to an input file, or `-` to read the
+//! input from stdin. Without an argument the bundled `inputs/dayN.txt` is used, if there is one.
+
+use std::{
+    borrow::Cow,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use eyre::{eyre, Result, WrapErr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Bundled,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn from_args() -> Self {
+        std::env::args()
+            .nth(1)
+            .map(Self::from)
+            .unwrap_or(Self::Bundled)
+    }
+
+    pub fn read(&self, bundled: Option<&'static str>) -> Result<Cow<'static, str>> {
+        match self {
+            Self::Bundled => bundled.map(Cow::Borrowed).ok_or_else(|| {
+                eyre!("no bundled input for this day, pass a path or `-` for stdin")
+            }),
+            Self::Stdin => read_stdin().map(Cow::Owned),
+            Self::File(path) => read_file(path).map(Cow::Owned),
+        }
+    }
+}
+
+impl From<String> for InputSource {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "-" => Self::Stdin,
+            _ => Self::File(value.into()),
+        }
+    }
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .wrap_err("reading input from stdin")
+        .map(|_| input)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).wrap_err_with(|| format!("reading input file: {path:?}"))
+}
+
+/// Loads the input chosen on the command line, falling back to `bundled`.
+pub fn load(bundled: Option<&'static str>) -> Result<Cow<'static, str>> {
+    InputSource::from_args().read(bundled)
+}
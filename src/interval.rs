@@ -0,0 +1,181 @@
+//! Half-open integer intervals and sets of them, for puzzles that move whole ranges at once.
+
+use std::{cmp::Ordering, fmt::Debug};
+
+use itertools::Itertools;
+use tap::prelude::*;
+
+/// The integers in `start..end`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { start, end } = self;
+        write!(f, "{start}..{end}")
+    }
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval of `length` integers starting at `start`.
+    pub const fn with_length(start: i64, length: i64) -> Self {
+        Self::new(start, start + length)
+    }
+
+    pub fn len(self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+            .pipe(|intersection| (!intersection.is_empty()).then_some(intersection))
+    }
+
+    pub fn shifted(self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-touching intervals.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet(Vec<Interval>);
+
+impl Debug for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(&self.0).finish()
+    }
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts the intervals and merges the ones that overlap or touch.
+    fn normalized(intervals: impl IntoIterator<Item = Interval>) -> Self {
+        intervals
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .sorted()
+            .coalesce(|previous, next| match previous.end.cmp(&next.start) {
+                Ordering::Less => Err((previous, next)),
+                _ => Ok(Interval::new(previous.start, previous.end.max(next.end))),
+            })
+            .collect_vec()
+            .pipe(Self)
+    }
+
+    pub fn intervals(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.0
+            .binary_search_by(|interval| match interval {
+                _ if interval.end <= value => Ordering::Less,
+                _ if value < interval.start => Ordering::Greater,
+                _ => Ordering::Equal,
+            })
+            .is_ok()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.0.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.0.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        *self = Self::normalized(self.intervals().chain([interval]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.intervals().chain(other.intervals()))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut left, mut right) = (self.intervals().peekable(), other.intervals().peekable());
+        let mut intersection = vec![];
+        while let (Some(&one), Some(&another)) = (left.peek(), right.peek()) {
+            intersection.extend(one.intersection(another));
+            if one.end <= another.end {
+                left.next();
+            }
+            if another.end <= one.end {
+                right.next();
+            }
+        }
+        Self(intersection)
+    }
+
+    /// Everything in `self` that is not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut removed = other.intervals().peekable();
+        let mut difference = vec![];
+        for mut interval in self.intervals() {
+            while let Some(&cut) = removed.peek() {
+                if cut.end <= interval.start {
+                    removed.next();
+                    continue;
+                }
+                if interval.end <= cut.start {
+                    break;
+                }
+                difference.push(Interval::new(interval.start, cut.start));
+                interval.start = cut.end;
+                if interval.end < cut.end {
+                    break;
+                }
+                removed.next();
+            }
+            difference.push(interval);
+        }
+        Self::normalized(difference)
+    }
+
+    /// Every value moved by `offset`.
+    pub fn shifted(&self, offset: i64) -> Self {
+        self.intervals()
+            .map(|interval| interval.shifted(offset))
+            .collect_vec()
+            .pipe(Self)
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self::normalized([interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        Self::normalized(intervals)
+    }
+}
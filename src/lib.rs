@@ -1 +1,65 @@
+use std::fmt::Display;
 
+use eyre::Result;
+use parse::ParseError;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod runner;
+
+/// A single day's puzzle, split into parsing and the two parts.
+///
+/// The parsed input may borrow from the raw text, so parsing happens once and both parts
+/// share the result.
+pub trait Solution {
+    /// Day of December the puzzle was published on.
+    const DAY: u8;
+    /// Puzzle input bundled with the repository, see `inputs/`.
+    const INPUT: Option<&'static str>;
+
+    type Input<'input>;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Malformed input is reported with the line and column it was found on.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Part1>;
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Part2>;
+}
+
+/// Solves both parts of `S` against the input chosen on the command line and prints them.
+pub fn run<S: Solution>() -> Result<()> {
+    let input = input::load(S::INPUT)?;
+    let parsed = S::parse(&input)?;
+    println!("part 1: {}", S::part_1(&parsed)?);
+    println!("part 2: {}", S::part_2(&parsed)?);
+    Ok(())
+}
@@ -0,0 +1,196 @@
+//! Number theory and exact arithmetic shared by the days whose answers combine cycles, need
+//! more precision than floats have, or count the points inside a polygon.
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use itertools::Itertools;
+
+/// Greatest common divisor, possibly negative for signed integers.
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Copy + Default + PartialEq + Rem<Output = T>,
+{
+    while b != T::default() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    match (a, b) {
+        (0, _) | (_, 0) => 0,
+        (a, b) => a / gcd(a, b) * b,
+    }
+}
+
+/// `(g, x, y)` with `a * x + b * y == g`, `g` being the greatest common divisor.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        b => {
+            let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+            (g, y, x - a.div_euclid(b) * y)
+        }
+    }
+}
+
+/// Solves `t ≡ residue (mod modulus)` for every pair at once, as a residue modulo the least
+/// common multiple of the moduli. The moduli need not be coprime, in which case there may be
+/// no solution at all.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(residue, modulus), (other, other_modulus)| {
+            let (g, x, _) = extended_gcd(modulus, other_modulus);
+            let difference = other - residue;
+            if difference % g != 0 {
+                return None;
+            }
+            let lcm = modulus / g * other_modulus;
+            let reduced = other_modulus / g;
+            let steps = (difference / g).rem_euclid(reduced) * x.rem_euclid(reduced) % reduced;
+            Some(((residue + modulus * steps).rem_euclid(lcm), lcm))
+        })
+}
+
+/// Lattice points strictly inside the polygon through `corners`, when its edges pass through
+/// `boundary` lattice points in all. The shoelace formula gives the polygon's area, and Pick's
+/// theorem, `area = interior + boundary / 2 - 1`, turns that into the points inside.
+pub fn interior_points(corners: &[(i128, i128)], boundary: i128) -> i128 {
+    let twice_area = corners
+        .iter()
+        .circular_tuple_windows()
+        .map(|(&(row, column), &(next_row, next_column))| column * next_row - next_column * row)
+        .sum::<i128>()
+        .abs();
+    (twice_area - boundary) / 2 + 1
+}
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// `numerator / denominator`, `None` when dividing by zero.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        (denominator != 0).then(|| {
+            let divisor = gcd(numerator, denominator).abs() * denominator.signum();
+            Self {
+                numerator: numerator / divisor,
+                denominator: denominator / divisor,
+            }
+        })
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    /// The value, if it is a whole number.
+    pub fn to_integer(self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl Debug for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            denominator => write!(f, "{}/{denominator}", self.numerator),
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, so cross multiplying keeps the order
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            ..self
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+        .expect("denominators are never zero")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+        .expect("denominators are never zero")
+    }
+}
+
+impl Div for Rational {
+    type Output = Option<Self>;
+
+    /// `None` when dividing by zero.
+    fn div(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
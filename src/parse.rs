@@ -0,0 +1,92 @@
+//! Errors for malformed puzzle input, pointing at the line and column they were found on.
+//!
+//! Parsers work on slices of the original input, so an error is located by where the
+//! offending slice sits inside the input rather than by threading positions around.
+
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole line the error points into.
+    pub snippet: String,
+    pub message: String,
+}
+
+/// Byte offset of `at` inside `input`, when `at` was sliced out of it.
+fn offset(input: &str, at: &str) -> Option<usize> {
+    (at.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|offset| offset + at.len() <= input.len())
+}
+
+impl ParseError {
+    /// Error pointing at the start of `at`, which has to be a slice of `input` (a line, a token
+    /// split off a line, ...). Anything else points at the start of the input.
+    pub fn at(input: &str, at: &str, message: impl Display) -> Self {
+        let offset = offset(input, at).unwrap_or_default();
+        let line_start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |newline| offset + newline);
+        Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error at the very end of `input`, for when something is missing.
+    pub fn at_end(input: &str, message: impl Display) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Re-anchors an error found while parsing `section` on its own to the `input` the
+    /// section was sliced out of.
+    pub fn within(self, input: &str, section: &str) -> Self {
+        let start = Self::at(input, section, "");
+        Self {
+            column: match self.line {
+                1 => start.column + self.column - 1,
+                _ => self.column,
+            },
+            line: start.line + self.line - 1,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            line,
+            column,
+            snippet,
+            message,
+        } = self;
+        let gutter = line.to_string().len();
+        writeln!(f, "line {line}, column {column}: {message}")?;
+        writeln!(f, "{line} | {snippet}")?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Turns any error into a [`ParseError`] pointing at `at`, see [`ParseError::at`].
+pub trait Located<T> {
+    fn located(self, input: &str, at: &str) -> Result<T, ParseError>;
+}
+
+impl<T, E: Display> Located<T> for Result<T, E> {
+    fn located(self, input: &str, at: &str) -> Result<T, ParseError> {
+        // `{:#}` renders the whole context chain of an `eyre::Report`
+        self.map_err(|error| ParseError::at(input, at, format!("{error:#}")))
+    }
+}
@@ -0,0 +1,92 @@
+//! Type-erased access to every day, used by the `aoc` binary.
+
+use eyre::Result;
+use strum::{Display, EnumString};
+
+use crate::{
+    day1::Day1, day10::Day10, day11::Day11, day12::Day12, day13::Day13, day14::Day14, day15::Day15,
+    day16::Day16, day17::Day17, day18::Day18, day19::Day19, day2::Day2, day20::Day20, day21::Day21,
+    day22::Day22, day23::Day23, day24::Day24, day25::Day25, day3::Day3, day4::Day4, day5::Day5,
+    day6::Day6, day7::Day7, day8::Day8, day9::Day9, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString, Display)]
+pub enum Part {
+    #[strum(serialize = "1")]
+    One,
+    #[strum(serialize = "2")]
+    Two,
+}
+
+impl Part {
+    pub const fn all() -> [Self; 2] {
+        [Self::One, Self::Two]
+    }
+}
+
+/// Parses `input` once and solves the requested parts of `S`, rendering each answer.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<String>> {
+    let parsed = S::parse(input)?;
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::One => S::part_1(&parsed).map(|answer| answer.to_string()),
+            Part::Two => S::part_2(&parsed).map(|answer| answer.to_string()),
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub input: Option<&'static str>,
+    pub solve: fn(&str, &[Part]) -> Result<Vec<String>>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            input: S::INPUT,
+            solve: solve::<S>,
+        }
+    }
+}
+
+impl std::fmt::Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day({})", self.day)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<Day1>(),
+    Day::of::<Day2>(),
+    Day::of::<Day3>(),
+    Day::of::<Day4>(),
+    Day::of::<Day5>(),
+    Day::of::<Day6>(),
+    Day::of::<Day7>(),
+    Day::of::<Day8>(),
+    Day::of::<Day9>(),
+    Day::of::<Day10>(),
+    Day::of::<Day11>(),
+    Day::of::<Day12>(),
+    Day::of::<Day13>(),
+    Day::of::<Day14>(),
+    Day::of::<Day15>(),
+    Day::of::<Day16>(),
+    Day::of::<Day17>(),
+    Day::of::<Day18>(),
+    Day::of::<Day19>(),
+    Day::of::<Day20>(),
+    Day::of::<Day21>(),
+    Day::of::<Day22>(),
+    Day::of::<Day23>(),
+    Day::of::<Day24>(),
+    Day::of::<Day25>(),
+];
+
+pub fn day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
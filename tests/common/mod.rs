@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+
+use aoc2023::Solution;
+use eyre::{ContextCompat, Result};
+
+pub fn part_1<S: Solution>(input: &str) -> Result<S::Part1> {
+    S::parse(input)
+        .map_err(eyre::Report::from)
+        .and_then(|parsed| S::part_1(&parsed))
+}
+
+pub fn part_2<S: Solution>(input: &str) -> Result<S::Part2> {
+    S::parse(input)
+        .map_err(eyre::Report::from)
+        .and_then(|parsed| S::part_2(&parsed))
+}
+
+pub fn bundled<S: Solution>() -> Result<&'static str> {
+    S::INPUT.with_context(|| format!("day {} has no bundled input", S::DAY))
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use aoc2023::day1::Day1;
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+const SAMPLE_SPELLED_OUT: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day1>(SAMPLE)?, 142);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day1>(SAMPLE_SPELLED_OUT)?, 281);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day1>(bundled::<Day1>()?)?, 54968);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day1>(bundled::<Day1>()?)?, 54094);
+    Ok(())
+}
+
+#[test]
+fn non_ascii_text_is_skipped() -> Result<()> {
+    assert_eq!(part_1::<Day1>("é\nà1b2é\n")?, 12);
+    assert_eq!(part_2::<Day1>("é\nàoneé2twö\n")?, 12);
+    Ok(())
+}
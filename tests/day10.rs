@@ -0,0 +1,214 @@
+mod common;
+
+use aoc2023::{
+    day10::{Day10, Interior, Tile},
+    grid::Position,
+    Solution,
+};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+use itertools::Itertools;
+
+const SQUARE_LOOP: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+
+const COMPLEX_LOOP: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+const ENCLOSED: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+const SQUEEZED: &str = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+
+const LARGER: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+const JUNK: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+#[test]
+fn part_1_square_loop() -> Result<()> {
+    assert_eq!(part_1::<Day10>(SQUARE_LOOP)?, 4);
+    Ok(())
+}
+
+#[test]
+fn part_1_complex_loop() -> Result<()> {
+    assert_eq!(part_1::<Day10>(COMPLEX_LOOP)?, 8);
+    Ok(())
+}
+
+#[test]
+fn part_2_enclosed() -> Result<()> {
+    assert_eq!(part_2::<Day10>(ENCLOSED)?, 4);
+    Ok(())
+}
+
+#[test]
+fn part_2_squeezed() -> Result<()> {
+    assert_eq!(part_2::<Day10>(SQUEEZED)?, 4);
+    Ok(())
+}
+
+#[test]
+fn part_2_larger() -> Result<()> {
+    assert_eq!(part_2::<Day10>(LARGER)?, 8);
+    Ok(())
+}
+
+#[test]
+fn part_2_junk() -> Result<()> {
+    assert_eq!(part_2::<Day10>(JUNK)?, 10);
+    Ok(())
+}
+
+#[test]
+fn scanline_agrees_with_area() -> Result<()> {
+    for (maze, enclosed) in [(ENCLOSED, 4), (SQUEEZED, 4), (LARGER, 8), (JUNK, 10)] {
+        let maze = Day10::parse(maze)?;
+        assert_eq!(maze.enclosed(Interior::Scanline)?, enclosed);
+        assert_eq!(maze.enclosed(Interior::Area)?, enclosed);
+    }
+    Ok(())
+}
+
+#[test]
+fn start_shape() -> Result<()> {
+    let maze = Day10::parse(SQUARE_LOOP)?;
+    assert_eq!(maze.start_shape()?, Tile::try_from('F')?);
+    assert_eq!(
+        Day10::parse(COMPLEX_LOOP)?.start_shape()?,
+        Tile::try_from('F')?
+    );
+    let resolved = maze.resolved()?;
+    assert_eq!(
+        format!("{resolved:?}").lines().nth(1),
+        Some("[7, F, -, 7, |]")
+    );
+    Ok(())
+}
+
+#[test]
+fn start_needs_two_connections() -> Result<()> {
+    for maze in ["...\n.S.\n...\n", ".|.\n-S-\n...\n"] {
+        assert!(Day10::parse(maze)?.start_shape().is_err(), "{maze}");
+    }
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day10>(bundled::<Day10>()?)?, 6875);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day10>(bundled::<Day10>()?)?, 471);
+    Ok(())
+}
+
+#[test]
+fn unknown_tile_is_located() {
+    let error = Day10::parse(".....\n.S-7.\n.|X|.\n.L-J.\n.....\n").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 3));
+    assert!(error.message.contains("unexpected tile"));
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    let error = Day10::parse(".....\n.S-7.\n.|.|\n").unwrap_err();
+    assert_eq!(error.line, 3);
+}
+
+const LOOPS_AND_FRAGMENTS: &str = "\
+F7.F--7
+LJ.|..|
+.-.L--J
+F-7....
+";
+
+#[test]
+fn every_loop_and_fragment() -> Result<()> {
+    let survey = Day10::parse(LOOPS_AND_FRAGMENTS)?.survey()?;
+    let loops = survey
+        .loops
+        .iter()
+        .map(|found| (found.tiles[0], found.len(), found.enclosed))
+        .collect_vec();
+    assert_eq!(
+        loops,
+        [(Position::new(0, 0), 4, 0), (Position::new(0, 3), 10, 2)]
+    );
+    assert_eq!(
+        survey.fragments,
+        [
+            vec![Position::new(2, 1)],
+            vec![
+                Position::new(3, 0),
+                Position::new(3, 1),
+                Position::new(3, 2)
+            ],
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn survey_finds_the_main_loop() -> Result<()> {
+    let survey = Day10::parse(bundled::<Day10>()?)?.survey()?;
+    assert!(survey
+        .loops
+        .iter()
+        .any(|found| found.len() == 2 * 6875 && found.enclosed == 471));
+    Ok(())
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use aoc2023::{day11::Day11, Solution};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day11>(SAMPLE)?, 374);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day11>(SAMPLE)?, 82000210);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day11>(bundled::<Day11>()?)?, 10422930);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day11>(bundled::<Day11>()?)?, 699909023130);
+    Ok(())
+}
+
+#[test]
+fn empty_image_is_rejected() {
+    for input in ["", "\n\n"] {
+        let error = Day11::parse(input).unwrap_err();
+        assert!(error.message.contains("no image rows"), "{input:?}");
+    }
+}
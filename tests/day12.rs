@@ -0,0 +1,133 @@
+mod common;
+
+use aoc2023::{
+    day12::{self, arrangements, count_arrangements, Day12, UNFOLD},
+    Solution,
+};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+use itertools::Itertools;
+
+const SAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day12>(SAMPLE)?, 21);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day12>(SAMPLE)?, 525152);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day12>(bundled::<Day12>()?)?, 7110);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day12>(bundled::<Day12>()?)?, 1566786613613);
+    Ok(())
+}
+
+fn unfolded(row: &str) -> String {
+    day12::unfolded(&row.chars().collect_vec(), &[], UNFOLD)
+        .0
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn unfolds_five_copies_joined_by_unknowns() {
+    assert_eq!(unfolded(".#"), ".#?.#?.#?.#?.#");
+    assert_eq!(
+        unfolded("???.###"),
+        "???.###????.###????.###????.###????.###"
+    );
+}
+
+#[test]
+fn unfold_factor() -> Result<()> {
+    let rows = Day12::parse(SAMPLE)?;
+    let counts = |copies| {
+        rows.iter()
+            .map(|(springs, sizes)| {
+                let (springs, sizes) = day12::unfolded(springs, sizes, copies);
+                count_arrangements(&springs, &sizes)
+            })
+            .collect_vec()
+    };
+    assert_eq!(counts(1), [1, 4, 1, 1, 4, 10]);
+    assert_eq!(counts(2), [1, 32, 1, 2, 20, 150]);
+    assert_eq!(counts(5).iter().sum::<u128>(), 525152);
+    Ok(())
+}
+
+#[test]
+fn enumerates_arrangements() -> Result<()> {
+    let rows = Day12::parse(SAMPLE)?;
+    let (springs, sizes) = &rows[1];
+    assert_eq!(
+        arrangements(springs, sizes).collect_vec(),
+        [
+            ".#...#....###.",
+            ".#....#...###.",
+            "..#..#....###.",
+            "..#...#...###.",
+        ]
+    );
+    for (springs, sizes) in &rows {
+        let all = arrangements(springs, sizes).collect_vec();
+        assert_eq!(all.len() as u128, count_arrangements(springs, sizes));
+        assert!(all.iter().all_unique());
+        for arrangement in &all {
+            let groups = arrangement
+                .split('.')
+                .filter(|group| !group.is_empty())
+                .map(str::len)
+                .collect_vec();
+            assert_eq!(&groups, sizes, "{arrangement}");
+            assert!(arrangement
+                .chars()
+                .zip(springs)
+                .all(|(spring, known)| *known == '?' || spring == *known));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn skips_ahead_in_unfolded_rows() -> Result<()> {
+    let rows = Day12::parse(SAMPLE)?;
+    let (springs, sizes) = day12::unfolded(&rows[5].0, &rows[5].1, UNFOLD);
+    let count = count_arrangements(&springs, &sizes);
+    assert_eq!(count, 506250);
+    let last = arrangements(&springs, &sizes)
+        .skip(count as usize - 1)
+        .collect_vec();
+    assert_eq!(last.len(), 1);
+    let middle = arrangements(&springs, &sizes)
+        .skip(1000)
+        .take(3)
+        .collect_vec();
+    let mut walked = arrangements(&springs, &sizes);
+    (0..1000).for_each(|_| drop(walked.next()));
+    assert_eq!(middle, walked.take(3).collect_vec());
+    assert!(arrangements(&springs, &sizes)
+        .skip(250_000)
+        .take(2)
+        .tuple_windows()
+        .all(|(one, next)| one < next));
+    Ok(())
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use aoc2023::{day13::Day13, Solution};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day13>(SAMPLE)?, 405);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day13>(SAMPLE)?, 400);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day13>(bundled::<Day13>()?)?, 29213);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day13>(bundled::<Day13>()?)?, 37453);
+    Ok(())
+}
+
+#[test]
+fn errors_in_later_patterns_point_into_the_whole_input() {
+    let Err(error) = Day13::parse("#.\n.#\n\n##\n#x\n") else {
+        panic!("parsed a pattern with an unknown cell");
+    };
+    assert_eq!((error.line, error.column), (5, 2));
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use aoc2023::{
+    day14::{Cycle, Day14, Simulation},
+    Solution,
+};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day14>(SAMPLE)?, 136);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    let spun = part_2::<Day14>(SAMPLE)?;
+    assert_eq!(spun.load, 64);
+    assert_eq!(
+        spun.cycle,
+        Some(Cycle {
+            offset: 3,
+            period: 7
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day14>(bundled::<Day14>()?)?, 113525);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    let spun = part_2::<Day14>(bundled::<Day14>()?)?;
+    assert_eq!(spun.load, 101292);
+    assert_eq!(
+        spun.cycle,
+        Some(Cycle {
+            offset: 94,
+            period: 84
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn spinning_a_period_further_gives_the_same_load() -> Result<()> {
+    let simulation = Simulation::new(Day14::parse(SAMPLE)?);
+    for cycles in 3..20 {
+        assert_eq!(
+            simulation.spin(cycles)?.load,
+            simulation.spin(cycles + 7)?.load,
+            "after {cycles} spin cycles"
+        );
+    }
+    assert_eq!(simulation.spin(1)?.cycle, None);
+    Ok(())
+}
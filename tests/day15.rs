@@ -0,0 +1,49 @@
+mod common;
+
+use aoc2023::{
+    day15::{ascii_code, hash, Day15},
+    Solution,
+};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day15>(SAMPLE)?, 1320);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day15>(SAMPLE)?, 145);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day15>(bundled::<Day15>()?)?, 512283);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day15>(bundled::<Day15>()?)?, 215827);
+    Ok(())
+}
+
+#[test]
+fn hashes_single_step() {
+    assert_eq!(ascii_code('H'), 72);
+    assert_eq!(hash("HASH"), 52);
+}
+
+#[test]
+fn step_without_operation_is_located() {
+    let error = Day15::parse("rn=1,cm,qp=3").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 6));
+    assert_eq!(error.snippet, "rn=1,cm,qp=3");
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use aoc2023::day16::Day16;
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day16>(SAMPLE)?, 46);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day16>(SAMPLE)?, 51);
+    Ok(())
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use aoc2023::{
+    day17::{least_heat_loss, Crucible, Day17},
+    Solution,
+};
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+const UNFORTUNATE: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day17>(SAMPLE)?, 102);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day17>(SAMPLE)?, 94);
+    Ok(())
+}
+
+#[test]
+fn part_2_has_to_move_far_enough_before_stopping() -> Result<()> {
+    assert_eq!(part_2::<Day17>(UNFORTUNATE)?, 71);
+    Ok(())
+}
+
+#[test]
+fn unconstrained_crucible_takes_the_cheapest_path() -> Result<()> {
+    let grid = Day17::parse("19\n11\n")?;
+    let crucible = Crucible {
+        min_run: 1,
+        max_run: usize::MAX,
+    };
+    assert_eq!(least_heat_loss(&grid, crucible), Some(2));
+    Ok(())
+}
+
+#[test]
+fn single_tile_loses_no_heat() -> Result<()> {
+    assert_eq!(part_1::<Day17>("7\n")?, 0);
+    assert_eq!(part_2::<Day17>("7\n")?, 0);
+    Ok(())
+}
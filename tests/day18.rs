@@ -0,0 +1,102 @@
+mod common;
+
+use aoc2023::{
+    day18::{lagoon_volume, Day18, Step},
+    grid::Direction,
+    Solution,
+};
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day18>(SAMPLE)?, 62);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day18>(SAMPLE)?, 952408144115);
+    Ok(())
+}
+
+#[test]
+fn reads_both_forms() -> Result<()> {
+    let plan = Day18::parse(SAMPLE)?;
+    assert_eq!(
+        plan[0].plain,
+        Step {
+            direction: Direction::East,
+            distance: 6
+        }
+    );
+    assert_eq!(
+        plan[0].hex,
+        Step {
+            direction: Direction::East,
+            distance: 461937
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn trench_has_to_close() {
+    let open = [Direction::East, Direction::South, Direction::West].map(|direction| Step {
+        direction,
+        distance: 2,
+    });
+    assert!(lagoon_volume(&open).is_err());
+}
+
+#[test]
+fn bad_hex_is_located() {
+    let error = Day18::parse("R 6 (#70c710)\nD 5 (#0dc57)\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 5));
+}
+
+#[test]
+fn negative_distance_is_located() {
+    let error = Day18::parse("R -3 (#70c710)\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 3));
+    assert!(error.message.contains("non-negative"));
+}
+
+#[test]
+fn huge_lagoon_is_an_error() {
+    let square = [
+        Direction::East,
+        Direction::South,
+        Direction::West,
+        Direction::North,
+    ]
+    .map(|direction| Step {
+        direction,
+        distance: u32::MAX,
+    });
+    let error = lagoon_volume(&square).unwrap_err();
+    assert!(error.to_string().contains("too large"));
+}
+
+#[test]
+fn empty_plan_is_an_error() {
+    assert!(lagoon_volume(&[]).is_err());
+    assert!(part_1::<Day18>("").is_err());
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use aoc2023::{
+    day19::{Day19, Target, RATINGS},
+    interval::Interval,
+    Solution,
+};
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day19>(SAMPLE)?, 19114);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day19>(SAMPLE)?, 167409079868000);
+    Ok(())
+}
+
+#[test]
+fn accepted_combinations_match_running_every_part() -> Result<()> {
+    let system = Day19::parse("in{x<3:A,m>2:R,a<2:lo,A}\nlo{s>1:A,R}\n\n")?;
+    let small = Interval::new(1, 5);
+    let cube = std::array::from_fn(|_| small.into());
+    let accepted = (1..5)
+        .flat_map(|x| (1..5).flat_map(move |m| (1..5).map(move |a| [x, m, a])))
+        .flat_map(|[x, m, a]| (1..5).map(move |s| [x, m, a, s]))
+        .map(|part| system.accepts(&part))
+        .filter(|accepted| accepted.as_ref().is_ok_and(|&accepted| accepted))
+        .count();
+    assert_eq!(
+        system.accepted_combinations(Target::Workflow("in"), cube)?,
+        accepted as i64
+    );
+    Ok(())
+}
+
+#[test]
+fn everything_is_accepted_without_conditions() -> Result<()> {
+    let system = Day19::parse("in{A}\n\n")?;
+    let cube = std::array::from_fn(|_| RATINGS.into());
+    assert_eq!(
+        system.accepted_combinations(Target::Workflow("in"), cube)?,
+        4000_i64.pow(4)
+    );
+    Ok(())
+}
+
+#[test]
+fn looping_workflows_are_an_error() -> Result<()> {
+    let system = Day19::parse("in{x<10:a,A}\na{in}\n\n{x=1,m=1,a=1,s=1}\n")?;
+    assert!(system.accepts(&[1, 1, 1, 1]).is_err());
+    assert!(part_2::<Day19>("in{x<10:a,A}\na{in}\n\n").is_err());
+    Ok(())
+}
+
+#[test]
+fn unknown_category_is_located() {
+    let error = Day19::parse("in{x<10:A,R}\npx{q>5:A,R}\n\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+}
+
+#[test]
+fn nothing_is_greater_than_the_largest_value() -> Result<()> {
+    assert_eq!(part_2::<Day19>("in{x>9223372036854775807:A,R}\n\n")?, 0);
+    Ok(())
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use aoc2023::{
+    day20::{Day20, Pulse},
+    Solution,
+};
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+const SAMPLE_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+/// Two counters resetting after 3 and 5 presses, inverted into a conjunction feeding `rx`.
+const COUNTERS: &str = "\
+broadcaster -> a0, b0
+%a0 -> a1, ca
+%a1 -> ca
+&ca -> a0, ia
+&ia -> hub
+%b0 -> b1, cb
+%b1 -> b2
+%b2 -> cb
+&cb -> b0, b1, ib
+&ib -> hub
+&hub -> rx
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day20>(SAMPLE)?, 32000000);
+    Ok(())
+}
+
+#[test]
+fn part_1_sample_2() -> Result<()> {
+    assert_eq!(part_1::<Day20>(SAMPLE_2)?, 11687500);
+    Ok(())
+}
+
+#[test]
+fn part_2_counters() -> Result<()> {
+    assert_eq!(part_2::<Day20>(COUNTERS)?, 15);
+    Ok(())
+}
+
+#[test]
+fn part_2_matches_pressing() -> Result<()> {
+    let mut circuit = Day20::parse(COUNTERS)?;
+    let presses = (1..)
+        .find(|_| {
+            let mut low = false;
+            circuit.press(|sent| low |= sent.to == "rx" && sent.pulse == Pulse::Low);
+            low
+        })
+        .unwrap_or_default();
+    assert_eq!(part_2::<Day20>(COUNTERS)?, presses);
+    Ok(())
+}
+
+#[test]
+fn unknown_module_type() {
+    let Err(error) = Day20::parse("broadcaster -> a\n$a -> b\n") else {
+        panic!("parsed an unknown module type");
+    };
+    assert_eq!((error.line, error.column), (2, 1));
+}
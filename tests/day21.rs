@@ -0,0 +1,70 @@
+mod common;
+
+use aoc2023::{day21::Day21, Solution};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+/// Like the real input: open edges, and an open row and column through the start.
+const OPEN_CROSS: &str = "\
+...........
+.##..#..#..
+.#...#...#.
+...#.#.##..
+.#...#.....
+.....S.....
+..##.#..#..
+.#...#.#...
+...#.#...#.
+.#...#..#..
+...........
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    let garden = Day21::parse(SAMPLE)?;
+    assert_eq!(garden.reachable(6), 16);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    let garden = Day21::parse(SAMPLE)?;
+    for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+        assert_eq!(garden.reachable_repeated(steps), plots, "{steps} steps");
+    }
+    Ok(())
+}
+
+#[test]
+fn part_2_extrapolated() -> Result<()> {
+    let garden = Day21::parse(OPEN_CROSS)?;
+    for steps in [5 + 11 * 6, 5 + 11 * 9, 3 + 11 * 8] {
+        assert_eq!(
+            garden.reachable_extrapolated(steps)?,
+            garden.reachable_repeated(steps) as u64,
+            "{steps} steps"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn second_start() {
+    let Err(error) = Day21::parse("S.\n.S\n") else {
+        panic!("parsed two starting plots");
+    };
+    assert_eq!((error.line, error.column), (2, 2));
+}
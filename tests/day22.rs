@@ -0,0 +1,46 @@
+mod common;
+
+use aoc2023::{
+    day22::{Day22, Stack},
+    Solution,
+};
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day22>(SAMPLE)?, 5);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day22>(SAMPLE)?, 7);
+    Ok(())
+}
+
+#[test]
+fn support_graph() -> Result<()> {
+    let stack = Stack::settle(&Day22::parse(SAMPLE)?);
+    let [a, b, c, d, e, f, g] = [0, 1, 2, 3, 4, 5, 6];
+    assert_eq!(stack.supports(a), [b, c]);
+    assert_eq!(stack.supported_by(d), [b, c]);
+    assert_eq!(stack.supported_by(g), [f]);
+    assert!(stack.supported_by(a).is_empty());
+    assert!(!stack.is_safe(a) && stack.is_safe(b) && !stack.is_safe(f));
+    assert_eq!(stack.bricks[g].start[2], 5);
+    assert_eq!(stack.falling_if_removed(a), 6);
+    assert_eq!(stack.falling_if_removed(f), 1);
+    assert_eq!(stack.falling_if_removed(e), 0);
+    Ok(())
+}
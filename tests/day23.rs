@@ -0,0 +1,70 @@
+mod common;
+
+use aoc2023::{
+    day23::{Day23, Graph},
+    grid::Position,
+    Solution,
+};
+use common::{part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day23>(SAMPLE)?, 94);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day23>(SAMPLE)?, 154);
+    Ok(())
+}
+
+#[test]
+fn junction_graph() -> Result<()> {
+    let trails = Day23::parse(SAMPLE)?;
+    let graph = trails.graph(false)?;
+    // start, end and the seven tiles where paths meet
+    assert_eq!(graph.junctions.len(), 9);
+    let [(first, length)] = graph.edges[graph.start][..] else {
+        panic!("start leads to more than one corridor");
+    };
+    assert_eq!((graph.junctions[first], length), (Position::new(5, 3), 15));
+    let slippery = trails.graph(true)?;
+    let edges = |graph: &Graph| graph.edges.iter().map(Vec::len).sum::<usize>();
+    assert_eq!(edges(&graph), 2 * edges(&slippery));
+    Ok(())
+}
+
+#[test]
+fn missing_exit_is_located() {
+    let error = Day23::parse("#.#\n#.#\n###\n").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 1));
+    assert!(error.message.contains("0 paths"));
+}
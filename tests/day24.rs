@@ -0,0 +1,82 @@
+mod common;
+
+use aoc2023::{
+    day24::{crossings_inside, rock, Day24, Hailstone},
+    math::Rational,
+    Solution,
+};
+use common::part_2;
+use eyre::Result;
+
+const SAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    let hail = Day24::parse(SAMPLE)?;
+    assert_eq!(crossings_inside(&hail, 7..=27), 2);
+    Ok(())
+}
+
+#[test]
+fn crossing_point() -> Result<()> {
+    let hail = Day24::parse(SAMPLE)?;
+    let thirds = |numerator| Rational::new(numerator, 3);
+    assert_eq!(
+        hail[0].crossing_xy(hail[1]),
+        thirds(43).zip(thirds(46)).map(|(x, y)| [x, y])
+    );
+    // parallel
+    assert_eq!(hail[1].crossing_xy(hail[2]), None);
+    // in the past of the first hailstone
+    assert_eq!(hail[0].crossing_xy(hail[4]), None);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(
+        rock(&Day24::parse(SAMPLE)?)?,
+        Hailstone {
+            position: [24, 13, 10],
+            velocity: [-3, 1, 2],
+        }
+    );
+    assert_eq!(part_2::<Day24>(SAMPLE)?, 47);
+    Ok(())
+}
+
+/// Coordinates this large overflow `i64` products and lose precision as `f64`.
+#[test]
+fn part_2_large_coordinates() -> Result<()> {
+    let rock = Hailstone {
+        position: [
+            287_430_900_705_823,
+            451_620_998_712_421,
+            260_730_677_041_648,
+        ],
+        velocity: [-128, -257, 98],
+    };
+    let hail = [
+        (3, [-31, 44, 211]),
+        (5, [17, -300, 59]),
+        (11, [98, 5, -3]),
+        (2, [-200, 91, 77]),
+    ]
+    .map(|(time, velocity): (i128, [i128; 3])| {
+        let hit = rock.at(time * 100_000_000_000);
+        Hailstone {
+            position: std::array::from_fn(|axis| {
+                hit[axis] - time * 100_000_000_000 * velocity[axis]
+            }),
+            velocity,
+        }
+    });
+    assert_eq!(self::rock(&hail)?, rock);
+    Ok(())
+}
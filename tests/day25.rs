@@ -0,0 +1,45 @@
+mod common;
+
+use aoc2023::{day25::Day25, Solution};
+use common::part_1;
+use eyre::Result;
+use itertools::Itertools;
+use tap::prelude::*;
+
+const SAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day25>(SAMPLE)?, 54);
+    Ok(())
+}
+
+#[test]
+fn cut_wires() -> Result<()> {
+    let wiring = Day25::parse(SAMPLE)?;
+    let cut = wiring.cut(3).expect("three wires split the sample");
+    let wires = cut
+        .wires
+        .iter()
+        .map(|&(one, other)| [one, other].tap_mut(|wire| wire.sort()))
+        .sorted()
+        .collect_vec();
+    assert_eq!(wires, [["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+    assert_eq!(cut.sizes.iter().sorted().collect_vec(), [&6, &9]);
+    assert_eq!(wiring.cut(2), None);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use aoc2023::{day3::Day3, Solution};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day3>(SAMPLE)?, 4361);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day3>(SAMPLE)?, 467835);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day3>(bundled::<Day3>()?)?, 532331);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day3>(bundled::<Day3>()?)?, 82301120);
+    Ok(())
+}
+
+#[test]
+fn non_ascii_symbol_is_located() {
+    let error = Day3::parse("467..\n..#é.\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use aoc2023::{day4::Day4, Solution};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day4>(SAMPLE)?, 13);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day4>(SAMPLE)?, 30);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day4>(bundled::<Day4>()?)?, 33950);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day4>(bundled::<Day4>()?)?, 14814534);
+    Ok(())
+}
+
+#[test]
+fn malformed_number_is_located() {
+    let error = Day4::parse("Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61 30\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 12));
+}
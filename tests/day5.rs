@@ -0,0 +1,123 @@
+mod common;
+
+use aoc2023::{
+    day5::{Day5, MAPPING_ORDER},
+    interval::Interval,
+    Solution,
+};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day5>(SAMPLE)?, 35);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day5>(SAMPLE)?, 46);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day5>(bundled::<Day5>()?)?, 806029445);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day5>(bundled::<Day5>()?)?, 59370572);
+    Ok(())
+}
+
+#[test]
+fn composed_mapping_matches_applying_each_map() -> Result<()> {
+    let almanac = Day5::parse(SAMPLE)?;
+    let seed_to_location = almanac.seed_to_location()?;
+    for seed in 0..120 {
+        let location = MAPPING_ORDER
+            .iter()
+            .fold(seed, |value, kind| almanac.maps[kind].apply(value));
+        assert_eq!(seed_to_location.apply(seed), location, "seed {seed}");
+    }
+    Ok(())
+}
+
+#[test]
+fn inverse_finds_the_seed_for_a_location() -> Result<()> {
+    let almanac = Day5::parse(SAMPLE)?;
+    let location_to_seed = almanac.seed_to_location()?.inverse()?;
+    // seed 13 is the one ending up at location 35
+    assert_eq!(location_to_seed.apply(35), 13);
+    for location in 0..120 {
+        let seed = location_to_seed.apply(location);
+        assert_eq!(almanac.seed_to_location()?.apply(seed), location);
+    }
+    Ok(())
+}
+
+/// Part 2's answer is reached by a seed, and every lower location traced back misses the seeds.
+fn check_part_2_backwards(input: &str, lowest: i64) -> Result<()> {
+    let almanac = Day5::parse(input)?;
+    let location_to_seed = almanac.seed_to_location()?.inverse()?;
+    let seeds = almanac.seed_ranges()?;
+    assert!(seeds.contains(location_to_seed.apply(lowest)));
+    let below = location_to_seed.map_ranges(&Interval::new(0, lowest).into());
+    assert!(below.intersection(&seeds).is_empty());
+    Ok(())
+}
+
+#[test]
+fn part_2_sample_backwards() -> Result<()> {
+    check_part_2_backwards(SAMPLE, 46)
+}
+
+#[test]
+fn part_2_real_backwards() -> Result<()> {
+    check_part_2_backwards(bundled::<Day5>()?, 59370572)
+}
+
+#[test]
+fn unpaired_seed_is_an_error() {
+    let input = SAMPLE.replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1);
+    assert!(part_1::<Day5>(&input).is_ok());
+    assert!(part_2::<Day5>(&input).is_err());
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use aoc2023::day6::Day6;
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day6>(SAMPLE)?, 288);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day6>(SAMPLE)?, 71503);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day6>(bundled::<Day6>()?)?, 505494);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day6>(bundled::<Day6>()?)?, 23632299);
+    Ok(())
+}
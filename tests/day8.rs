@@ -0,0 +1,107 @@
+mod common;
+
+use aoc2023::{day8::Day8, Solution};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const REPEATED_DIRECTIONS: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const GHOSTS: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+/// Ghosts on `Z` at 2 mod 5 and 3 mod 7, which only meet through the remainder theorem.
+const OFFSET_GHOSTS: &str = "\
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11D, 11D)
+11D = (11E, 11E)
+11E = (11B, 11B)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22D, 22D)
+22D = (22E, 22E)
+22E = (22F, 22F)
+22F = (22G, 22G)
+22G = (22B, 22B)
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day8>(SAMPLE)?, 2);
+    Ok(())
+}
+
+#[test]
+fn part_1_repeated_directions() -> Result<()> {
+    assert_eq!(part_1::<Day8>(REPEATED_DIRECTIONS)?, 6);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day8>(GHOSTS)?, 6);
+    Ok(())
+}
+
+#[test]
+fn part_2_offset_cycles() -> Result<()> {
+    let network = Day8::parse(OFFSET_GHOSTS)?;
+    let ghost = network.ghost("11A")?;
+    assert_eq!(
+        (ghost.cycle_start, ghost.period, ghost.cycle),
+        (1, 5, vec![2])
+    );
+    assert_eq!(part_2::<Day8>(OFFSET_GHOSTS)?, 17);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day8>(bundled::<Day8>()?)?, 12599);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day8>(bundled::<Day8>()?)?, 8245452805243);
+    Ok(())
+}
+
+#[test]
+fn empty_directions_are_located() {
+    let error = Day8::parse("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert!(error.message.contains("no directions"));
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use aoc2023::{day9::Day9, Solution};
+use common::{bundled, part_1, part_2};
+use eyre::Result;
+
+const SAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+#[test]
+fn part_1_sample() -> Result<()> {
+    assert_eq!(part_1::<Day9>(SAMPLE)?, 114);
+    Ok(())
+}
+
+#[test]
+fn part_2_sample() -> Result<()> {
+    assert_eq!(part_2::<Day9>(SAMPLE)?, 2);
+    Ok(())
+}
+
+#[test]
+fn part_1_real() -> Result<()> {
+    assert_eq!(part_1::<Day9>(bundled::<Day9>()?)?, 2043677056);
+    Ok(())
+}
+
+#[test]
+fn part_2_real() -> Result<()> {
+    assert_eq!(part_2::<Day9>(bundled::<Day9>()?)?, 1062);
+    Ok(())
+}
+
+#[test]
+fn malformed_number_is_located() {
+    let error = Day9::parse("0 3 6\n1 three 6\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.snippet, "1 three 6");
+}
@@ -0,0 +1,102 @@
+use aoc2023::grid::{Direction, Grid, Position};
+use eyre::{bail, Result};
+use itertools::Itertools;
+
+/// Two rows of three, so every transform changes the shape.
+fn wide() -> Result<Grid<char>> {
+    Ok("abc\ndef\n".parse()?)
+}
+
+#[test]
+fn transposed_swaps_rows_and_columns() -> Result<()> {
+    let transposed = wide()?.transposed();
+    assert_eq!((transposed.width(), transposed.height()), (2, 3));
+    assert_eq!(transposed.to_string(), "ad\nbe\ncf\n");
+    assert_eq!(transposed.transposed(), wide()?);
+    Ok(())
+}
+
+#[test]
+fn rotations() -> Result<()> {
+    let grid = wide()?;
+    assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotated_counter_clockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotated_clockwise().rotated_counter_clockwise(), grid);
+    let full_turn = (0..4).fold(grid.clone(), |grid, _| grid.rotated_clockwise());
+    assert_eq!(full_turn, grid);
+    Ok(())
+}
+
+#[test]
+fn rows_and_columns() -> Result<()> {
+    let grid = wide()?;
+    assert_eq!(
+        grid.rows().collect_vec(),
+        [['a', 'b', 'c'], ['d', 'e', 'f']]
+    );
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.column(3).count(), 0);
+    assert_eq!(grid.row(2), None);
+    Ok(())
+}
+
+#[test]
+fn set_and_swap_are_bounds_checked() -> Result<()> {
+    let mut grid = wide()?;
+    assert_eq!(grid.set(Position::new(1, 2), 'x')?, 'f');
+    assert!(grid.set(Position::new(2, 0), 'x').is_err());
+    assert!(grid.set(Position::new(0, 3), 'x').is_err());
+    grid.swap(Position::new(0, 0), Position::new(1, 2))?;
+    assert!(grid.swap(Position::new(0, 0), Position::new(0, 3)).is_err());
+    assert_eq!(grid.to_string(), "xbc\ndea\n");
+    assert_eq!(grid.get(Position::new(5, 5)), None);
+    Ok(())
+}
+
+#[test]
+fn neighbours_at_the_corners() -> Result<()> {
+    let grid = wide()?;
+    let directions = |row, column| {
+        grid.neighbours(Position::new(row, column))
+            .map(|(_, direction)| direction)
+            .sorted()
+            .collect_vec()
+    };
+    assert_eq!(directions(0, 0), [Direction::East, Direction::South]);
+    assert_eq!(directions(1, 2), [Direction::North, Direction::West]);
+    assert_eq!(
+        grid.neighbours_with_diagonals(Position::new(0, 0))
+            .sorted()
+            .collect_vec(),
+        [
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(1, 1)
+        ]
+    );
+    assert_eq!(
+        grid.neighbours_with_diagonals(Position::new(1, 2)).count(),
+        3
+    );
+    assert_eq!(grid.neighbour(Position::new(0, 2), Direction::East), None);
+    Ok(())
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    let error = "abc\nde\nfgh\n".parse::<Grid<char>>().unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert!(error.message.contains("row has 2 cells, expected 3"));
+}
+
+#[test]
+fn bad_cells_are_located() {
+    let Err(error) = Grid::parse_with("..#\n.x.\n", |c| match c {
+        '.' | '#' => Ok(c),
+        other => bail!("unexpected cell {other:?}"),
+    }) else {
+        panic!("parsed an unexpected cell");
+    };
+    assert_eq!((error.line, error.column), (2, 2));
+    assert!(error.message.contains("unexpected cell 'x'"));
+}
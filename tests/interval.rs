@@ -0,0 +1,57 @@
+use aoc2023::interval::{Interval, IntervalSet};
+
+fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+    intervals
+        .iter()
+        .map(|&(start, end)| Interval::new(start, end))
+        .collect()
+}
+
+fn intervals(set: &IntervalSet) -> Vec<(i64, i64)> {
+    set.intervals()
+        .map(|Interval { start, end }| (start, end))
+        .collect()
+}
+
+#[test]
+fn overlapping_and_touching_intervals_merge() {
+    let merged = set(&[(5, 8), (0, 3), (3, 4), (7, 10), (12, 12)]);
+    assert_eq!(intervals(&merged), [(0, 4), (5, 10)]);
+    assert_eq!(merged.len(), 9);
+    assert!(merged.contains(0) && merged.contains(9));
+    assert!(!merged.contains(4) && !merged.contains(10) && !merged.contains(12));
+    assert_eq!((merged.min(), merged.max()), (Some(0), Some(9)));
+}
+
+#[test]
+fn union() {
+    let union = set(&[(0, 2), (10, 12)]).union(&set(&[(1, 5), (20, 21)]));
+    assert_eq!(intervals(&union), [(0, 5), (10, 12), (20, 21)]);
+}
+
+#[test]
+fn intersection() {
+    let intersection = set(&[(0, 5), (8, 12), (20, 30)]).intersection(&set(&[(3, 9), (11, 25)]));
+    assert_eq!(
+        intervals(&intersection),
+        [(3, 5), (8, 9), (11, 12), (20, 25)]
+    );
+    assert!(set(&[(0, 5)]).intersection(&set(&[(5, 9)])).is_empty());
+}
+
+#[test]
+fn difference() {
+    let difference = set(&[(0, 10), (20, 30)]).difference(&set(&[(2, 4), (8, 22), (25, 26)]));
+    assert_eq!(intervals(&difference), [(0, 2), (4, 8), (22, 25), (26, 30)]);
+    assert!(set(&[(3, 6)]).difference(&set(&[(0, 10)])).is_empty());
+    assert_eq!(
+        intervals(&set(&[(3, 6)]).difference(&IntervalSet::new())),
+        [(3, 6)]
+    );
+}
+
+#[test]
+fn shifted() {
+    let shifted = set(&[(0, 2), (5, 6)]).shifted(-10);
+    assert_eq!(intervals(&shifted), [(-10, -8), (-5, -4)]);
+}
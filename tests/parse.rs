@@ -0,0 +1,40 @@
+use aoc2023::parse::{Located, ParseError};
+
+const INPUT: &str = "first line\nsecond 12x\nthird\n";
+
+#[test]
+fn locates_slices_of_the_input() {
+    let token = &INPUT[18..21];
+    assert_eq!(token, "12x");
+    let error = ParseError::at(INPUT, token, "not a number");
+    assert_eq!((error.line, error.column), (2, 8));
+    assert_eq!(error.snippet, "second 12x");
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 8: not a number\n2 | second 12x\n  |        ^"
+    );
+}
+
+#[test]
+fn missing_input_points_past_the_last_line() {
+    let error = ParseError::at_end(INPUT, "missing");
+    assert_eq!((error.line, error.column), (4, 1));
+    assert_eq!(error.snippet, "");
+}
+
+#[test]
+fn sections_are_reanchored_to_the_whole_input() {
+    let section = &INPUT[11..];
+    let error = ParseError::at(section, &section[7..], "bad").within(INPUT, section);
+    assert_eq!((error.line, error.column), (2, 8));
+    let error = ParseError::at(section, &section[13..], "bad").within(INPUT, section);
+    assert_eq!((error.line, error.column), (3, 3));
+}
+
+#[test]
+fn located_keeps_the_error_message() {
+    let token = &INPUT[18..21];
+    let error = token.parse::<u32>().located(INPUT, token).unwrap_err();
+    assert_eq!(error.message, "invalid digit found in string");
+    assert_eq!((error.line, error.column), (2, 8));
+}